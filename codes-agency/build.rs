@@ -0,0 +1,75 @@
+use codes_common::build::{
+    default_finalize_for, input_file_name, make_default_renderer, process, Data, DataRow,
+    SimpleData,
+};
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use std::error::Error;
+use tera::Value;
+
+const TYPE_NAME: &str = "Agency";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    process(
+        || Ok(SimpleData::new(TYPE_NAME)),
+        |data| process_agency_xml(data, "agencies.xml").and_then(check_parent_agencies),
+        default_finalize_for,
+        make_default_renderer("agency._rs", "agency.rs"),
+    )
+}
+
+fn process_agency_xml(mut data: SimpleData, file_name: &str) -> Result<SimpleData, Box<dyn Error>> {
+    let file_name = input_file_name(file_name);
+    let mut reader = Reader::from_file(file_name)?;
+    reader.config_mut().trim_text(true);
+
+    let mut buffer = Vec::new();
+    let mut element: Option<&'static str> = None;
+    let mut row = DataRow::new();
+
+    loop {
+        match reader.read_event_into(&mut buffer)? {
+            Event::Start(e) => {
+                element = match e.name().as_ref() {
+                    b"ShortName" => Some("short_name"),
+                    b"Name" => Some("name"),
+                    b"Url" => Some("url"),
+                    b"ParentAgency" => Some("parent_agency"),
+                    _ => None,
+                };
+            }
+            Event::Text(e) => {
+                if let Some(field) = element {
+                    let text = e.unescape()?.to_string();
+                    row.insert(field.to_string(), Value::String(text));
+                }
+            }
+            Event::End(e) => {
+                if e.name().as_ref() == b"Agency" {
+                    let id = match row.get("short_name") {
+                        Some(Value::String(id)) => id.clone(),
+                        _ => return Err("Agency element has no ShortName".into()),
+                    };
+                    data.insert_row(&id, std::mem::take(&mut row));
+                }
+                element = None;
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buffer.clear();
+    }
+
+    Ok(data)
+}
+
+fn check_parent_agencies(data: SimpleData) -> Result<SimpleData, Box<dyn Error>> {
+    for (id, row) in data.rows() {
+        if let Some(Value::String(parent)) = row.get("parent_agency") {
+            if !data.contains(parent) {
+                return Err(format!("Agency {} has unknown parent agency {}", id, parent).into());
+            }
+        }
+    }
+    Ok(data)
+}
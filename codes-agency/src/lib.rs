@@ -25,6 +25,24 @@ assert_eq!(ISO_4217.long_ref(), Some(&"ISO 4217:2015"));
 assert_eq!(ISO_4217.title(), "Currency codes");
 assert_eq!(ISO_4217.url(), "https://www.iso.org/iso-4217-currency-codes.html");
 ```
+
+Agencies may be part of a larger organization, in which case the parent
+agency is available and the agency URN includes the complete hierarchy.
+
+```rust
+use codes_agency::Agency;
+
+assert_eq!(Agency::UNSD.parent_agency(), Some(&Agency::UN));
+assert_eq!(Agency::TC68.agency_urn(), "urn:agency:iso/tc68");
+```
+
+# Features
+
+By default only the `serde` feature is enabled.
+
+* `serde` - Enables serialization of the [Agency] and [Standard] types.
+* `registry` - Adds the `registry` module, a registry of the standards for all
+  types declared using the [standardized_type] macro.
 */
 
 #![warn(
@@ -56,7 +74,8 @@ assert_eq!(ISO_4217.url(), "https://www.iso.org/iso-4217-currency-codes.html");
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -77,35 +96,20 @@ use serde::{Deserialize, Serialize};
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-///
-/// This enumeration allows for the identification of well-known standards agencies. This is
-/// useful in documenting crates that implement such standards.
-///
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub enum Agency {
-    /// [GS1](https://www.gs1.org)
-    GS1,
-    /// [Internet Assigned Numbers Authority](https://www.iana.org)
-    IANA,
-    /// [IEEE](https://www.ieee.org)
-    IEEE,
-    /// [The Internet Engineering Task Force](https://www.ietf.org)
-    IETF,
-    /// [International Organization for Standardization](https://www.iso.org)
-    ISO,
-    /// [The United Nations](https://www.un.org)
-    UN,
-}
-
-/// Provides an array of all defined [Agency] codes, useful for queries.
-pub const ALL_CODES: [Agency; 4] = [Agency::IANA, Agency::IEEE, Agency::IETF, Agency::ISO];
+// The [Agency] enumeration, and the constant `ALL_CODES`, are generated from
+// the file `data/agencies.xml` by the package build script.
+include!(concat!(env!("OUT_DIR"), "/agency.rs"));
 
 ///
 /// This structure allows for the description of a specific standard, or specification,
 /// issued by a well-known standards agency. Note that different versions of a standard
 /// should be different instances with *at least* different long references.
 ///
+/// In addition to the values provided to the constructors, the edition, date of
+/// publication, registration (or maintenance) authority, and any standard this
+/// one supersedes may be added using the `with_` methods, all of which are
+/// `const` and so may be used to construct constant values.
+///
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Standard {
@@ -114,6 +118,11 @@ pub struct Standard {
     long_ref: Option<&'static str>,
     title: &'static str,
     url: &'static str,
+    edition: Option<&'static str>,
+    publication_date: Option<&'static str>,
+    registration_authority: Option<Agency>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    supersedes: Option<&'static Standard>,
 }
 
 pub trait Standardized {
@@ -134,6 +143,12 @@ pub enum AgencyError {
 // Public Macros
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Implement the [Standardized] trait for `$type_name` returning `$standard`.
+/// When the `registry` feature is enabled this also adds the standard to the
+/// [registry] of all standards linked into the current program.
+///
+#[cfg(feature = "registry")]
 #[macro_export]
 macro_rules! standardized_type {
     ($type_name:ty, $standard:expr) => {
@@ -142,81 +157,35 @@ macro_rules! standardized_type {
                 &$standard
             }
         }
+
+        $crate::registry::inventory::submit! {
+            $crate::registry::Registration::new(&$standard, stringify!($type_name))
+        }
     };
 }
-// ------------------------------------------------------------------------------------------------
-// Implementations
-// ------------------------------------------------------------------------------------------------
 
-impl FromStr for Agency {
-    type Err = AgencyError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "GS1" => Ok(Self::GS1),
-            "IANA" => Ok(Self::IANA),
-            "IEEE" => Ok(Self::IEEE),
-            "IETF" => Ok(Self::IETF),
-            "ISO" => Ok(Self::ISO),
-            "UN" => Ok(Self::UN),
-            _ => Err(AgencyError::FromStr(s.to_string())),
+///
+/// Implement the [Standardized] trait for `$type_name` returning `$standard`.
+///
+#[cfg(not(feature = "registry"))]
+#[macro_export]
+macro_rules! standardized_type {
+    ($type_name:ty, $standard:expr) => {
+        impl $crate::Standardized for $type_name {
+            fn defining_standard() -> &'static $crate::Standard {
+                &$standard
+            }
         }
-    }
+    };
 }
 
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
 code_impl!(Agency, short_name);
 
 impl Agency {
-    ///
-    /// Return the short name, usually an acronym or abbreviation, of the agency.
-    /// This is usually the same set of characters as the variant name.
-    ///
-    pub const fn short_name(&self) -> &'static str {
-        match self {
-            Self::GS1 => "GS1",
-            Self::IANA => "IANA",
-            Self::IEEE => "IEEE",
-            Self::IETF => "IETF",
-            Self::ISO => "ISO",
-            Self::UN => "UN",
-        }
-    }
-
-    ///
-    /// A longer name, if one exists, for the agency.
-    ///
-    pub const fn name(&self) -> &'static str {
-        match self {
-            Self::GS1 => "GS1 AISBL",
-            Self::IANA => "Internet Assigned Numbers Authority",
-            Self::IEEE => "IEEE",
-            Self::IETF => "The Internet Engineering Task Force",
-            Self::ISO => "International Organization for Standardization",
-            Self::UN => "The United Nations",
-        }
-    }
-
-    ///
-    /// A URL for the agency.
-    ///
-    pub const fn url(&self) -> &'static str {
-        match self {
-            Self::GS1 => "https://www.gs1.org",
-            Self::IANA => "https://www.iana.org",
-            Self::IEEE => "https://www.ieee.org",
-            Self::IETF => "https://www.ietf.org",
-            Self::ISO => "https://www.iso.org",
-            Self::UN => "https://www.un.org",
-        }
-    }
-
-    ///
-    /// Some agencies are hierarchical, this returns a parent agency, if one exists.
-    ///
-    pub const fn parent_agency(&self) -> Option<&Self> {
-        None
-    }
-
     ///
     /// Return a [URN](https://www.rfc-editor.org/rfc/rfc8141) that identifies
     /// an agency. The *namespace identifier* is "agency" (this is not a
@@ -226,16 +195,19 @@ impl Agency {
     ///
     /// # Example
     ///
-    /// ```rust,ignore
+    /// ```rust
+    /// use codes_agency::Agency;
+    ///
     /// let urn = Agency::CEFACT.agency_urn();
     /// assert_eq!(&urn, "urn:agency:un/unece/cefact");
     /// ```
     ///
     pub fn agency_urn(&self) -> String {
         let mut path = vec![self.short_name().to_lowercase()];
-        let agency = self;
-        while let Some(agency) = agency.parent_agency() {
-            path.insert(0, agency.short_name().to_lowercase());
+        let mut agency = self;
+        while let Some(parent) = agency.parent_agency() {
+            path.insert(0, parent.short_name().to_lowercase());
+            agency = parent;
         }
         format!("urn:agency:{}", path.join("/"))
     }
@@ -259,6 +231,10 @@ impl Standard {
             long_ref: None,
             title,
             url,
+            edition: None,
+            publication_date: None,
+            registration_authority: None,
+            supersedes: None,
         }
     }
 
@@ -278,6 +254,54 @@ impl Standard {
             long_ref: Some(long_ref),
             title,
             url,
+            edition: None,
+            publication_date: None,
+            registration_authority: None,
+            supersedes: None,
+        }
+    }
+
+    ///
+    /// Return a copy of this standard with the edition, or version, set.
+    ///
+    pub const fn with_edition(self, edition: &'static str) -> Self {
+        Self {
+            edition: Some(edition),
+            ..self
+        }
+    }
+
+    ///
+    /// Return a copy of this standard with the date of publication set, this
+    /// should be in the ISO 8601 form `YYYY-MM-DD`, `YYYY-MM`, or `YYYY`.
+    ///
+    pub const fn with_publication_date(self, publication_date: &'static str) -> Self {
+        Self {
+            publication_date: Some(publication_date),
+            ..self
+        }
+    }
+
+    ///
+    /// Return a copy of this standard with the registration, or maintenance,
+    /// authority set. This is the agency that manages the values defined by
+    /// the standard on behalf of the controlling agency.
+    ///
+    pub const fn with_registration_authority(self, registration_authority: Agency) -> Self {
+        Self {
+            registration_authority: Some(registration_authority),
+            ..self
+        }
+    }
+
+    ///
+    /// Return a copy of this standard noting that it supersedes, or replaces,
+    /// the standard `supersedes`.
+    ///
+    pub const fn with_supersedes(self, supersedes: &'static Standard) -> Self {
+        Self {
+            supersedes: Some(supersedes),
+            ..self
         }
     }
 
@@ -315,6 +339,35 @@ impl Standard {
     pub const fn url(&self) -> &'static str {
         self.url
     }
+
+    ///
+    /// Return the edition, or version, of this standard if known.
+    ///
+    pub const fn edition(&self) -> Option<&'static str> {
+        self.edition
+    }
+
+    ///
+    /// Return the date this standard was published, if known.
+    ///
+    pub const fn publication_date(&self) -> Option<&'static str> {
+        self.publication_date
+    }
+
+    ///
+    /// Return the registration, or maintenance, authority for this standard,
+    /// if one exists.
+    ///
+    pub const fn registration_authority(&self) -> Option<Agency> {
+        self.registration_authority
+    }
+
+    ///
+    /// Return the standard that this standard supersedes, if any.
+    ///
+    pub const fn supersedes(&self) -> Option<&'static Standard> {
+        self.supersedes
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -336,3 +389,6 @@ impl std::error::Error for AgencyError {}
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "registry")]
+pub mod registry;
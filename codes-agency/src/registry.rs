@@ -0,0 +1,238 @@
+/*!
+Provides a registry of all the [Standard] instances used by types linked into
+the current program.
+
+Each package that uses the [standardized_type](crate::standardized_type)
+macro to implement the [Standardized](crate::Standardized) trait for a type
+also registers the corresponding standard here. This allows a program to
+report on the standards, and editions, it supports without knowing ahead of
+time which packages are included.
+
+# Example
+
+```rust
+use codes_agency::{standardized_type, Agency, Standard};
+use codes_agency::registry::{all_standards, find_by_short_ref, standardized_types};
+
+pub const ISO_4217: Standard = Standard::new_with_long_ref(
+    Agency::ISO,
+    "4217",
+    "ISO 4217:2015",
+    "Currency codes",
+    "https://www.iso.org/iso-4217-currency-codes.html",
+)
+.with_edition("2015")
+.with_registration_authority(Agency::SIX);
+
+pub struct CurrencyCode;
+
+standardized_type!(CurrencyCode, ISO_4217);
+
+assert!(all_standards().contains(&&ISO_4217));
+assert_eq!(find_by_short_ref("4217"), vec![&ISO_4217]);
+assert_eq!(standardized_types(&ISO_4217), vec!["CurrencyCode"]);
+```
+
+*/
+
+use crate::Standard;
+
+#[doc(hidden)]
+pub use inventory;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An entry in the registry associating a type with the standard that defines
+/// it. These are created by the [standardized_type](crate::standardized_type)
+/// macro.
+///
+#[derive(Debug)]
+pub struct Registration {
+    standard: &'static Standard,
+    type_name: &'static str,
+}
+
+inventory::collect!(Registration);
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return an iterator over all registrations, the order is not defined.
+///
+pub fn registrations() -> impl Iterator<Item = &'static Registration> {
+    inventory::iter::<Registration>.into_iter()
+}
+
+///
+/// Return all registered standards, without duplicates, ordered by agency,
+/// short reference, and long reference.
+///
+pub fn all_standards() -> Vec<&'static Standard> {
+    let mut standards: Vec<&'static Standard> = Vec::new();
+    for registration in registrations() {
+        if !standards.contains(&registration.standard) {
+            standards.push(registration.standard);
+        }
+    }
+    standards.sort_by_key(|standard| {
+        (
+            standard.agency().short_name(),
+            standard.short_ref(),
+            standard.long_ref().copied(),
+        )
+    });
+    standards
+}
+
+///
+/// Return all registered standards with the short reference `short_ref`,
+/// there may be more than one where different editions are registered.
+///
+pub fn find_by_short_ref(short_ref: &str) -> Vec<&'static Standard> {
+    all_standards()
+        .into_iter()
+        .filter(|standard| standard.short_ref() == short_ref)
+        .collect()
+}
+
+///
+/// Return the registered standard with the long reference `long_ref`, if one
+/// exists.
+///
+pub fn find_by_long_ref(long_ref: &str) -> Option<&'static Standard> {
+    registrations()
+        .map(|registration| registration.standard)
+        .find(|standard| standard.long_ref() == Some(&long_ref))
+}
+
+///
+/// Return the names of all types registered as defined by `standard`.
+///
+pub fn standardized_types(standard: &Standard) -> Vec<&'static str> {
+    let mut type_names: Vec<&'static str> = registrations()
+        .filter(|registration| registration.standard == standard)
+        .map(|registration| registration.type_name)
+        .collect();
+    type_names.sort_unstable();
+    type_names
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Registration {
+    #[doc(hidden)]
+    pub const fn new(standard: &'static Standard, type_name: &'static str) -> Self {
+        Self {
+            standard,
+            type_name,
+        }
+    }
+
+    ///
+    /// Return the standard that defines the registered type.
+    ///
+    pub const fn standard(&self) -> &'static Standard {
+        self.standard
+    }
+
+    ///
+    /// Return the name of the registered type, as written in the
+    /// [standardized_type](crate::standardized_type) macro.
+    ///
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Standard {
+    ///
+    /// Return the registered standard that supersedes this one, if any.
+    ///
+    pub fn superseded_by(&self) -> Option<&'static Standard> {
+        registrations()
+            .map(|registration| registration.standard)
+            .find(|standard| standard.supersedes() == Some(self))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Agency, Standardized};
+
+    const TEST_1: Standard = Standard::new_with_long_ref(
+        Agency::ISO,
+        "99999",
+        "ISO 99999:2001",
+        "Test codes",
+        "https://www.iso.org/standard/",
+    )
+    .with_edition("2001");
+
+    const TEST_2: Standard = Standard::new_with_long_ref(
+        Agency::ISO,
+        "99999",
+        "ISO 99999:2022",
+        "Test codes",
+        "https://www.iso.org/standard/",
+    )
+    .with_edition("2022")
+    .with_publication_date("2022-01")
+    .with_registration_authority(Agency::SWIFT)
+    .with_supersedes(&TEST_1);
+
+    struct OldCode;
+    struct NewCode;
+    struct OtherNewCode;
+
+    standardized_type!(OldCode, TEST_1);
+    standardized_type!(NewCode, TEST_2);
+    standardized_type!(OtherNewCode, TEST_2);
+
+    #[test]
+    fn test_standardized() {
+        assert_eq!(OldCode::defining_standard(), &TEST_1);
+        assert_eq!(NewCode::defining_standard(), &TEST_2);
+        assert_eq!(OtherNewCode::defining_standard(), &TEST_2);
+        assert_eq!(NewCode::defining_standard().edition(), Some("2022"));
+        assert_eq!(
+            NewCode::defining_standard().registration_authority(),
+            Some(Agency::SWIFT)
+        );
+    }
+
+    #[test]
+    fn test_all_standards() {
+        assert_eq!(all_standards(), vec![&TEST_1, &TEST_2]);
+        assert_eq!(find_by_short_ref("99999"), vec![&TEST_1, &TEST_2]);
+        assert!(find_by_short_ref("00000").is_empty());
+        assert_eq!(find_by_long_ref("ISO 99999:2022"), Some(&TEST_2));
+        assert_eq!(find_by_long_ref("ISO 99999"), None);
+    }
+
+    #[test]
+    fn test_standardized_types() {
+        assert_eq!(standardized_types(&TEST_1), vec!["OldCode"]);
+        assert_eq!(standardized_types(&TEST_2), vec!["NewCode", "OtherNewCode"]);
+    }
+
+    #[test]
+    fn test_supersession() {
+        assert_eq!(TEST_2.supersedes(), Some(&TEST_1));
+        assert_eq!(TEST_1.superseded_by(), Some(&TEST_2));
+        assert_eq!(TEST_2.superseded_by(), None);
+    }
+}
@@ -0,0 +1,99 @@
+use codes_check_digits::{iso_7064, luhn, Calculator};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Inputs
+// ------------------------------------------------------------------------------------------------
+
+// LEI, without the two check digits.
+const LEI_INPUT: &str = "YZ83GD8L7GG84979J5";
+
+// IBAN, with the BBAN moved before the country code and the check digits
+// removed as required by ISO 13616.
+const IBAN_INPUT: &str = "WEST12345698765432GB";
+
+// ISIN, without the check digit.
+const ISIN_INPUT: &str = "US037833100";
+
+// ------------------------------------------------------------------------------------------------
+// Baseline
+// ------------------------------------------------------------------------------------------------
+
+// The string based MOD 97-10 calculation used prior to version 0.1.4, this
+// builds a numeric string and then reduces it in chunks that fit a `u64`.
+
+const MAX_STRING_LEN: usize = "18446744073709551615".len() - 4;
+
+fn baseline_calculate_mod(s: &str, modulus: u16) -> u16 {
+    if s.len() <= MAX_STRING_LEN {
+        (u64::from_str(s).unwrap() % modulus as u64) as u16
+    } else {
+        baseline_calculate_mod(
+            &format!(
+                "{}{}",
+                baseline_calculate_mod(&s[..MAX_STRING_LEN], modulus),
+                &s[MAX_STRING_LEN..]
+            ),
+            modulus,
+        )
+    }
+}
+
+fn baseline_mod_97_10(s: &str) -> u16 {
+    let s: String = s
+        .chars()
+        .map(|c| match c {
+            '0'..='9' => c as u8 - b'0',
+            _ => c as u8 - b'A' + 10,
+        })
+        .map(|b| b.to_string())
+        .collect();
+    98 - baseline_calculate_mod(&format!("{}00", s), 97)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Benchmarks
+// ------------------------------------------------------------------------------------------------
+
+fn mod_97_10(c: &mut Criterion) {
+    let calculator = iso_7064::get_algorithm_instance(iso_7064::IsoVariant::Mod_97_10);
+
+    let mut group = c.benchmark_group("mod_97_10");
+    for (name, input) in [("lei", LEI_INPUT), ("iban", IBAN_INPUT)] {
+        group.bench_function(format!("{}/baseline", name), |b| {
+            b.iter(|| baseline_mod_97_10(black_box(input)))
+        });
+        group.bench_function(format!("{}/calculate", name), |b| {
+            b.iter(|| calculator.calculate(black_box(input)))
+        });
+        group.bench_function(format!("{}/create", name), |b| {
+            b.iter(|| calculator.create(black_box(input)))
+        });
+        group.bench_function(format!("{}/create_into_slice", name), |b| {
+            let mut buffer = [0u8; 64];
+            b.iter(|| {
+                calculator
+                    .create_into_slice(black_box(input), &mut buffer)
+                    .map(|s| s.len())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn luhn(c: &mut Criterion) {
+    let calculator = luhn::get_algorithm_instance();
+
+    let mut group = c.benchmark_group("luhn");
+    group.bench_function("isin/calculate", |b| {
+        b.iter(|| calculator.calculate(black_box(ISIN_INPUT)))
+    });
+    group.bench_function("isin/validate", |b| {
+        b.iter(|| calculator.validate(black_box("US0378331005")))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, mod_97_10, luhn);
+criterion_main!(benches);
@@ -1,9 +1,5 @@
 use crate::error::{invalid_alphabet, invalid_length, CheckDigitError};
-use std::ops::RangeInclusive;
-use std::str::FromStr;
-
-#[cfg(all(feature = "big_integer", not(target_family = "windows")))]
-use rug::Integer;
+use core::ops::RangeInclusive;
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -54,6 +50,7 @@ pub(crate) fn is_ascii_alpha_upper(s: &str) -> Result<(), CheckDigitError> {
     }
 }
 
+#[allow(dead_code)]
 #[inline(always)]
 pub(crate) fn is_ascii_alphanumeric_upper(s: &str) -> Result<(), CheckDigitError> {
     if s.chars().all(|c| matches!(c, '0'..='9' | 'A'..='Z')) {
@@ -63,6 +60,7 @@ pub(crate) fn is_ascii_alphanumeric_upper(s: &str) -> Result<(), CheckDigitError
     }
 }
 
+#[allow(dead_code)]
 #[inline(always)]
 pub(crate) fn is_ascii_alphanumeric_upper_no_vowels(s: &str) -> Result<(), CheckDigitError> {
     if s.chars()
@@ -76,24 +74,33 @@ pub(crate) fn is_ascii_alphanumeric_upper_no_vowels(s: &str) -> Result<(), Check
 
 // ========== String transform functions
 
-#[allow(dead_code)]
-#[inline(always)]
-pub(crate) fn string_to_byte_vecs(
-    s: &str,
-    f: fn(char) -> Vec<u8>,
-) -> impl Iterator<Item = u8> + '_ {
-    s.chars().flat_map(f)
-}
-
 #[allow(dead_code)]
 #[inline(always)]
 pub(crate) fn string_to_bytes(s: &str, f: fn(char) -> u8) -> impl Iterator<Item = u8> + '_ {
     s.chars().map(f)
 }
 
+///
+/// Return the decimal digits of the values produced by `f` for each
+/// character, so that `'A'` (10) becomes the two digits `1`, `0`.
+///
+#[allow(dead_code)]
 #[inline(always)]
-pub(crate) fn string_to_numeric_string(s: &str, f: fn(char) -> u8) -> String {
-    s.chars().map(f).map(|b| b.to_string()).collect()
+pub(crate) fn string_to_numeric_digits(
+    s: &str,
+    f: fn(char) -> u8,
+) -> impl DoubleEndedIterator<Item = u8> + '_ {
+    s.chars().map(f).flat_map(|v| {
+        let digits = [v / 100, (v / 10) % 10, v % 10];
+        let skip = if v >= 100 {
+            0
+        } else if v >= 10 {
+            1
+        } else {
+            2
+        };
+        digits.into_iter().skip(skip)
+    })
 }
 
 // ========== Character transform functions
@@ -129,6 +136,7 @@ pub(crate) fn ascii_alpha_to_u8(c: char) -> u8 {
     }
 }
 
+#[allow(dead_code)]
 #[inline(always)]
 pub(crate) fn ascii_alphanum_to_u8(c: char) -> u8 {
     let d = c as u8;
@@ -153,33 +161,31 @@ pub(crate) fn ascii_alphanum_or_star_to_u8(c: char) -> u8 {
 
 // ========== Math operations
 
-// The string length of u64::MAX
-const MAX_STRING_LEN: usize = "18446744073709551615".len() - 4;
-
-#[cfg(any(not(feature = "big_integer"), target_family = "windows"))]
+///
+/// Calculate the remainder of the (arbitrarily long) decimal number `s`
+/// divided by `modulus`. This is computed incrementally, one digit at a time,
+/// and so neither allocates nor requires a big integer type.
+///
+#[allow(dead_code)]
 #[inline(always)]
 pub(crate) fn calculate_mod(s: &str, modulus: u16) -> u16 {
-    if s.len() <= MAX_STRING_LEN {
-        (u64::from_str(s).unwrap() % modulus as u64) as u16
-    } else {
-        calculate_mod(
-            &format!(
-                "{}{}",
-                calculate_mod(&s[..MAX_STRING_LEN], modulus),
-                &s[MAX_STRING_LEN..]
-            ),
-            modulus,
-        )
-    }
+    calculate_mod_digits(s.bytes().map(|b| b - b'0'), modulus)
 }
 
-#[cfg(all(feature = "big_integer", not(target_family = "windows")))]
-pub(crate) fn calculate_mod(s: &str, modulus: u16) -> u16 {
-    if s.len() <= MAX_STRING_LEN {
-        (u64::from_str(s).unwrap() % modulus as u64) as u16
-    } else {
-        (Integer::from_str(s).unwrap() % modulus).to_u16().unwrap()
-    }
+///
+/// Calculate the remainder of the decimal number with the provided digits
+/// divided by `modulus`.
+///
+#[inline(always)]
+pub(crate) fn calculate_mod_digits(digits: impl Iterator<Item = u8>, modulus: u16) -> u16 {
+    // Only reduce the accumulator when another digit may overflow it.
+    const LIMIT: u64 = u64::MAX / 10 - 9;
+    let modulus = modulus as u64;
+    let r = digits.fold(0, |r, d| {
+        let r = if r > LIMIT { r % modulus } else { r };
+        r * 10 + d as u64
+    });
+    (r % modulus) as u16
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -190,6 +196,12 @@ pub(crate) fn calculate_mod(s: &str, modulus: u16) -> u16 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_numeric_digits() {
+        let digits: Vec<u8> = string_to_numeric_digits("9A8C", ascii_alphanum_to_u8).collect();
+        assert_eq!(digits, vec![9, 1, 0, 8, 1, 2]);
+    }
+
     #[test]
     fn test_small_number_mod() {
         assert_eq!(calculate_mod("6735", 97), 42);
@@ -0,0 +1,253 @@
+/*!
+Provides the types used by [crate::Calculator::suggest_corrections] to
+describe candidate corrections for a string that fails validation.
+
+# Example
+
+```rust
+use codes_check_digits::{correction::ErrorClass, gs1, Calculator};
+
+let calculator = gs1::get_algorithm_instance(gs1::CodeFormat::Gln);
+let corrections = calculator.suggest_corrections("94364657921O4");
+assert_eq!(corrections[0].code(), "9436465792104");
+assert_eq!(corrections[0].error(), ErrorClass::SingleSubstitution);
+assert_eq!(corrections[0].position(), 11);
+```
+
+*/
+
+use core::fmt::Display;
+
+#[cfg(feature = "alloc")]
+use crate::Calculator;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The classes of data entry error considered when validating, and
+/// correcting, check digits.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ErrorClass {
+    /// A single character was replaced by another, `"1234"` → `"1284"`.
+    SingleSubstitution,
+    /// Two adjacent characters were swapped, `"1234"` → `"1324"`.
+    AdjacentTransposition,
+}
+
+///
+/// A candidate correction, the string `code` is valid according to the
+/// algorithm and differs from the input by a single error of class `error`
+/// at `position`.
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Correction {
+    code: String,
+    error: ErrorClass,
+    position: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// Pairs of characters commonly confused when reading or typing codes.
+#[cfg(feature = "alloc")]
+const CONFUSABLE: &[(char, char)] = &[
+    ('0', 'O'),
+    ('0', 'D'),
+    ('0', 'Q'),
+    ('1', 'I'),
+    ('1', 'L'),
+    ('1', '7'),
+    ('2', 'Z'),
+    ('5', 'S'),
+    ('6', 'G'),
+    ('8', 'B'),
+    ('U', 'V'),
+];
+
+impl Display for ErrorClass {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::SingleSubstitution => "single substitution",
+                Self::AdjacentTransposition => "adjacent transposition",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl Display for Correction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ({} at {})", self.code, self.error, self.position)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Correction {
+    ///
+    /// The corrected, and valid, code.
+    ///
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    ///
+    /// The class of error this correction assumes was made.
+    ///
+    pub const fn error(&self) -> ErrorClass {
+        self.error
+    }
+
+    ///
+    /// The character index of the error, for a transposition this is the
+    /// first of the two characters.
+    ///
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Enumerate all single substitutions, using the calculator's alphabet, and
+/// adjacent transpositions of `s` that are valid. Corrections are ranked
+/// with substitutions of commonly confused characters first, then
+/// transpositions, and finally all other substitutions.
+///
+#[cfg(feature = "alloc")]
+pub(crate) fn suggest_corrections<C, T>(calculator: &C, s: &str) -> Vec<Correction>
+where
+    C: Calculator<T>,
+    T: Display + PartialEq,
+{
+    let mut corrections: Vec<(u8, Correction)> = Default::default();
+    if s.is_empty() || !s.is_ascii() || calculator.is_valid(s) {
+        return Default::default();
+    }
+    let chars: Vec<char> = s.chars().collect();
+
+    for (position, original) in chars.iter().enumerate() {
+        for replacement in calculator.alphabet().chars() {
+            if replacement != *original {
+                let mut candidate = chars.clone();
+                candidate[position] = replacement;
+                let code: String = candidate.into_iter().collect();
+                if calculator.is_valid(&code) {
+                    let rank = if is_confusable(*original, replacement) {
+                        0
+                    } else {
+                        2
+                    };
+                    corrections.push((
+                        rank,
+                        Correction {
+                            code,
+                            error: ErrorClass::SingleSubstitution,
+                            position,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    for position in 0..chars.len().saturating_sub(1) {
+        if chars[position] != chars[position + 1] {
+            let mut candidate = chars.clone();
+            candidate.swap(position, position + 1);
+            let code: String = candidate.into_iter().collect();
+            if calculator.is_valid(&code) {
+                corrections.push((
+                    1,
+                    Correction {
+                        code,
+                        error: ErrorClass::AdjacentTransposition,
+                        position,
+                    },
+                ));
+            }
+        }
+    }
+
+    corrections.sort_by_key(|(rank, correction)| (*rank, correction.position));
+    corrections
+        .into_iter()
+        .map(|(_, correction)| correction)
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn is_confusable(lhs: char, rhs: char) -> bool {
+    CONFUSABLE
+        .iter()
+        .any(|(a, b)| (*a == lhs && *b == rhs) || (*a == rhs && *b == lhs))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(
+    test,
+    feature = "alloc",
+    feature = "damm",
+    feature = "gs1",
+    feature = "iso_7064"
+))]
+mod tests {
+    use crate::correction::ErrorClass;
+    use crate::{damm, gs1, iso_7064, Calculator};
+
+    #[test]
+    fn test_transposition_suggested() {
+        let calculator = damm::get_algorithm_instance();
+        let corrections = calculator.suggest_corrections("7524");
+        assert!(corrections
+            .iter()
+            .any(|c| c.code() == "5724" && c.error() == ErrorClass::AdjacentTransposition));
+        assert!(corrections.iter().all(|c| calculator.is_valid(c.code())));
+    }
+
+    #[test]
+    fn test_valid_has_no_suggestions() {
+        let calculator = gs1::get_algorithm_instance(gs1::CodeFormat::Gln);
+        assert!(calculator.suggest_corrections("9436465792104").is_empty());
+    }
+
+    #[test]
+    fn test_ranking() {
+        // A confusable substitution is ranked before everything else.
+        let calculator = iso_7064::get_algorithm_instance(iso_7064::IsoVariant::Mod_11_10);
+        let corrections = calculator.suggest_corrections("79I625");
+        assert_eq!(corrections[0].code(), "791625");
+        assert_eq!(corrections.len(), 1);
+
+        // A transposition is ranked before other substitutions.
+        let calculator = damm::get_algorithm_instance();
+        let corrections = calculator.suggest_corrections("4683");
+        let transposition = corrections
+            .iter()
+            .position(|c| c.error() == ErrorClass::AdjacentTransposition)
+            .unwrap();
+        assert!(corrections[(transposition + 1)..]
+            .iter()
+            .all(|c| c.error() == ErrorClass::SingleSubstitution));
+    }
+}
@@ -0,0 +1,131 @@
+/*!
+The type [CheckDigitAlgorithm] provides an implementation of the check
+digit calculation as part of the CUSIP specification.
+
+# Example
+
+```rust
+use codes_check_digits::{cusip, Calculator};
+
+let calculator = cusip::get_algorithm_instance();
+assert!(calculator.is_valid("037833100"));
+assert!(calculator.validate("037833100").is_ok());
+assert_eq!(calculator.calculate("03783310"), Ok(0));
+```
+
+*/
+
+use crate::{
+    common::is_length_eq,
+    error::{invalid_alphabet, CheckDigitError},
+    Calculator,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Validate the Committee on Uniform Security Identification Procedures
+/// (CUSIP) numbers defined in ANSI X9.6. The algorithm is a modulus 10
+/// "double-add-double" over the values `0..=9` for digits, `10..=35` for
+/// letters, and `36`, `37`, `38` for the characters `'*'`, `'@'`, and `'#'`.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckDigitAlgorithm {}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+const SHARED_INSTANCE: CheckDigitAlgorithm = CheckDigitAlgorithm {};
+
+pub const fn get_algorithm_instance() -> &'static CheckDigitAlgorithm {
+    &SHARED_INSTANCE
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Calculator<u8> for CheckDigitAlgorithm {
+    fn name(&self) -> &'static str {
+        "Committee on Uniform Security Identification Procedures (CUSIP)"
+    }
+
+    fn alphabet(&self) -> &'static str {
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ*@#"
+    }
+
+    fn calculate(&self, s: &str) -> Result<u8, CheckDigitError> {
+        is_length_eq(s, 8)?;
+        is_cusip_alphabet(s)?;
+        let sum: u16 = s
+            .chars()
+            .map(cusip_char_to_u8)
+            .enumerate()
+            .map(|(i, v)| if i & 1 == 1 { v * 2 } else { v })
+            .map(|v| (v / 10 + v % 10) as u16)
+            .sum();
+
+        Ok(((10 - (sum % 10)) % 10) as u8)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline(always)]
+fn is_cusip_alphabet(s: &str) -> Result<(), CheckDigitError> {
+    if s.chars()
+        .all(|c| matches!(c, '0'..='9' | 'A'..='Z' | '*' | '@' | '#'))
+    {
+        Ok(())
+    } else {
+        Err(invalid_alphabet("ascii-alphanumeric-upper-or-*@#"))
+    }
+}
+
+#[inline(always)]
+fn cusip_char_to_u8(c: char) -> u8 {
+    let d = c as u8;
+    match d {
+        b'0'..=b'9' => d - b'0',
+        b'A'..=b'Z' => d - b'A' + 10,
+        b'*' => 36,
+        b'@' => 37,
+        b'#' => 38,
+        _ => panic!(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::cusip::CheckDigitAlgorithm;
+    use crate::Calculator;
+
+    #[test]
+    fn test_validate_numeric() {
+        let cusip = CheckDigitAlgorithm::default();
+        // Apple
+        assert!(cusip.is_valid("037833100"));
+        // Cisco
+        assert!(cusip.is_valid("17275R102"));
+        // Google
+        assert!(cusip.is_valid("38259P508"));
+        assert!(!cusip.is_valid("037833101"));
+    }
+
+    #[test]
+    fn test_calculate_special_characters() {
+        let cusip = CheckDigitAlgorithm::default();
+        assert_eq!(cusip.calculate("00000*@#"), Ok(8));
+        assert!(cusip.calculate("0378331$").is_err());
+        assert!(cusip.calculate("0378331").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+/*!
+The type [CheckDigitAlgorithm] provides an implementation of the
+[Damm Algorithm](https://en.wikipedia.org/wiki/Damm_algorithm).
+
+# Example
+
+```rust
+use codes_check_digits::{damm, Calculator};
+
+let calculator = damm::get_algorithm_instance();
+assert!(calculator.is_valid("5724"));
+assert!(calculator.validate("5724").is_ok());
+assert_eq!(calculator.calculate("572"), Ok(4));
+```
+
+*/
+
+use crate::{
+    common::{ascii_numeric_to_u8, is_ascii_numeric},
+    correction::ErrorClass,
+    error::CheckDigitError,
+    Calculator,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The Damm algorithm uses a totally anti-symmetric quasigroup of order 10
+/// to detect all single digit errors and all transposition errors of
+/// adjacent digits. Unlike Verhoeff it requires only a single table.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckDigitAlgorithm {}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+const SHARED_INSTANCE: CheckDigitAlgorithm = CheckDigitAlgorithm {};
+
+pub const fn get_algorithm_instance() -> &'static CheckDigitAlgorithm {
+    &SHARED_INSTANCE
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const QUASIGROUP: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+impl Calculator<u8> for CheckDigitAlgorithm {
+    fn name(&self) -> &'static str {
+        "Damm Algorithm"
+    }
+
+    fn alphabet(&self) -> &'static str {
+        "0123456789"
+    }
+
+    fn detected_errors(&self) -> &'static [ErrorClass] {
+        &[
+            ErrorClass::SingleSubstitution,
+            ErrorClass::AdjacentTransposition,
+        ]
+    }
+
+    fn calculate(&self, s: &str) -> Result<u8, CheckDigitError> {
+        is_ascii_numeric(s)?;
+        Ok(s.chars()
+            .map(ascii_numeric_to_u8)
+            .fold(0, |interim, d| QUASIGROUP[interim as usize][d as usize]))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::damm::CheckDigitAlgorithm;
+    use crate::Calculator;
+
+    #[test]
+    fn test_check_digits() {
+        let calculator = CheckDigitAlgorithm::default();
+        assert_eq!(calculator.calculate("572"), Ok(4));
+        assert_eq!(calculator.calculate("123456789"), Ok(4));
+        assert!(calculator.is_valid("5724"));
+        // Adjacent transposition
+        assert!(!calculator.is_valid("7524"));
+        assert!(calculator.calculate("57A").is_err());
+    }
+}
@@ -2,15 +2,20 @@
 Provides the crate's Error and Result types as well as helper
 functions.
 
+None of the types in this module allocate, and so they are all available
+when the crate is used without `std` or `alloc`.
+
  */
 
-use codes_common::CodeParseError;
-use std::{
-    fmt::{Debug, Display},
+use core::{
+    fmt::{Debug, Display, Write},
     ops::RangeInclusive,
 };
 use tracing::warn;
 
+#[cfg(feature = "std")]
+use codes_common::CodeParseError;
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -23,15 +28,35 @@ pub enum CheckDigitError {
     /// Input string length is invalid.
     InvalidLength { min: usize, max: usize, got: usize },
     /// Input string does not conform to the expected alphabet.
-    InvalidAlphabet { alphabet: String },
+    InvalidAlphabet { alphabet: &'static str },
     /// The calculated check digit does not match the input.
-    InvalidCheckDigit { expecting: String, got: String },
+    InvalidCheckDigit {
+        expecting: CheckDigits,
+        got: CheckDigits,
+    },
+    /// The buffer provided to [crate::Calculator::create_into_slice] is too
+    /// small for the result.
+    BufferTooSmall { required: usize, got: usize },
+    /// The writer provided to [crate::Calculator::create_into] returned an
+    /// error.
+    WriteFailed,
 }
 
 ///
 /// A Result type that specifically uses this crate's Error.
 ///
-pub type Result<T> = std::result::Result<T, CheckDigitError>;
+pub type Result<T> = core::result::Result<T, CheckDigitError>;
+
+///
+/// A fixed capacity string holding the check digit(s) reported in an error.
+/// Values longer than [CheckDigits::CAPACITY] bytes are replaced by `'?'`
+/// characters.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckDigits {
+    bytes: [u8; CheckDigits::CAPACITY],
+    len: usize,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -46,11 +71,7 @@ pub fn invalid_length(expecting: RangeInclusive<usize>, got: usize) -> CheckDigi
     }
 }
 
-pub fn invalid_alphabet<S>(alphabet: S) -> CheckDigitError
-where
-    S: Into<String>,
-{
-    let alphabet = alphabet.into();
+pub fn invalid_alphabet(alphabet: &'static str) -> CheckDigitError {
     warn!(
         "One or more input characters not in the alphabet {}",
         alphabet
@@ -65,40 +86,132 @@ where
 {
     warn!("Invalid check digit expecting {}, got {}", expecting, got);
     CheckDigitError::InvalidCheckDigit {
-        expecting: expecting.to_string(),
-        got: got.to_string(),
+        expecting: CheckDigits::from_display(expecting),
+        got: CheckDigits::from_display(got),
     }
 }
 
+pub fn buffer_too_small(required: usize, got: usize) -> CheckDigitError {
+    warn!("Output buffer too small, {} < {}", got, required);
+    CheckDigitError::BufferTooSmall { required, got }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
 impl Display for CheckDigitError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                CheckDigitError::InvalidLength { min, max, got } => format!(
-                    "Expecting input length in the range {}..={}, not {}",
-                    min, max, got
-                ),
-                CheckDigitError::InvalidAlphabet { alphabet } =>
-                    format!("Expecting characters from the alphabet {:?}", alphabet),
-                CheckDigitError::InvalidCheckDigit { expecting, got } => format!(
-                    "Invalid check digit in string, expecting {}, got {}",
-                    expecting, got
-                ),
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CheckDigitError::InvalidLength { min, max, got } => write!(
+                f,
+                "Expecting input length in the range {}..={}, not {}",
+                min, max, got
+            ),
+            CheckDigitError::InvalidAlphabet { alphabet } => {
+                write!(f, "Expecting characters from the alphabet {:?}", alphabet)
             }
-        )
+            CheckDigitError::InvalidCheckDigit { expecting, got } => write!(
+                f,
+                "Invalid check digit in string, expecting {}, got {}",
+                expecting, got
+            ),
+            CheckDigitError::BufferTooSmall { required, got } => write!(
+                f,
+                "Output buffer too small, requires {} bytes, not {}",
+                required, got
+            ),
+            CheckDigitError::WriteFailed => write!(f, "Failed to write to output"),
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for CheckDigitError {}
 
+#[cfg(feature = "std")]
 impl From<CheckDigitError> for CodeParseError {
     fn from(e: CheckDigitError) -> Self {
         Self::CheckDigit(Box::new(e))
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for CheckDigits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Debug for CheckDigits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Write for CheckDigits {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > Self::CAPACITY {
+            Err(core::fmt::Error)
+        } else {
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+}
+
+impl PartialEq<str> for CheckDigits {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for CheckDigits {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl CheckDigits {
+    ///
+    /// The maximum number of bytes stored.
+    ///
+    pub const CAPACITY: usize = 8;
+
+    ///
+    /// Return the check digit(s) as a string.
+    ///
+    pub fn as_str(&self) -> &str {
+        // Only whole `str` values are ever written, so this is always valid.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    ///
+    /// Format the value into a new instance, returning `None` if the
+    /// formatted value does not fit.
+    ///
+    pub(crate) fn try_from_display<T>(value: T) -> Option<Self>
+    where
+        T: Display,
+    {
+        let mut check_digits = Self {
+            bytes: [0; Self::CAPACITY],
+            len: 0,
+        };
+        write!(check_digits, "{}", value).ok()?;
+        Some(check_digits)
+    }
+
+    fn from_display<T>(value: T) -> Self
+    where
+        T: Display,
+    {
+        Self::try_from_display(value).unwrap_or(Self {
+            bytes: [b'?'; Self::CAPACITY],
+            len: Self::CAPACITY,
+        })
+    }
+}
@@ -17,10 +17,11 @@ assert_eq!(calculator.calculate("943646579210"), Ok(4));
 
 use crate::{
     common::{is_ascii_numeric, is_length_eq},
+    correction::ErrorClass,
     error::CheckDigitError,
     Calculator,
 };
-use std::fmt::Display;
+use core::fmt::Display;
 
 // ------------------------------------------------------------------------------------------------
 // Public Macros
@@ -81,7 +82,7 @@ pub const fn get_algorithm_instance(code_format: CodeFormat) -> CheckDigitAlgori
 // ------------------------------------------------------------------------------------------------
 
 impl Display for CodeFormat {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.name())
     }
 }
@@ -122,6 +123,16 @@ impl Calculator<u8> for CheckDigitAlgorithm {
         self.code_type.name()
     }
 
+    fn alphabet(&self) -> &'static str {
+        "0123456789"
+    }
+
+    fn detected_errors(&self) -> &'static [ErrorClass] {
+        // The weights 3 and 1 are co-prime with 10, but their difference is
+        // not, so transpositions such as "05" → "50" are not detected.
+        &[ErrorClass::SingleSubstitution]
+    }
+
     fn calculate(&self, s: &str) -> Result<u8, CheckDigitError> {
         is_length_eq(s, self.length - self.number_of_check_digit_chars())?;
         is_ascii_numeric(s)?;
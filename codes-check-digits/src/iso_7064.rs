@@ -8,26 +8,47 @@ Also, see Code of Federal Regulations, Title 12 - Banks and Banking,
 [Appendix C to Part 1003—Procedures for Generating a Check Digit and
 Validating a ULI](https://www.govinfo.gov/content/pkg/CFR-2016-title12-vol8/xml/CFR-2016-title12-vol8-part1003-appC.xml).
 
+The standard defines two families of check character systems:
+
+* **Pure systems**; these use a single modulus *M* and radix *r* and may
+  produce either one or two check characters. Where a single check character
+  system has a check value of *M - 1* that exceeds the data alphabet a
+  supplementary character is used, `'X'` for MOD 11-2 and `'*'` for MOD 37-2.
+* **Hybrid systems**; these use two moduli, *M* and *M+1*, and always produce
+  a single check character from the same alphabet as the data.
+
 # Example
 
-YYYYY
+```rust
+use codes_check_digits::{iso_7064, Calculator};
+
+let calculator = iso_7064::get_algorithm_instance(iso_7064::IsoVariant::Mod_11_2);
+assert!(calculator.is_valid("079X"));
+assert_eq!(calculator.calculate("079").unwrap().to_string(), "X");
+
+let calculator = iso_7064::get_algorithm_instance(iso_7064::IsoVariant::Mod_1271_36);
+assert_eq!(calculator.create("ISO79"), Ok("ISO793W".to_string()));
+```
 
 */
 
 use crate::{
     common::{
-        ascii_alphanum_to_u8, calculate_mod, is_ascii_alpha_upper, is_ascii_alphanumeric_upper,
-        is_ascii_numeric, string_to_numeric_string,
+        ascii_alpha_to_u8, ascii_alphanum_to_u8, ascii_numeric_to_u8, calculate_mod_digits,
+        is_ascii_alpha_upper, is_ascii_alphanumeric_upper, is_ascii_numeric,
+        string_to_numeric_digits,
     },
+    correction::ErrorClass,
+    error::CheckDigitError,
     Calculator,
 };
-use std::fmt::Display;
+use core::fmt::Display;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub enum IsoVariant {
     ///
@@ -50,6 +71,10 @@ pub enum IsoVariant {
     /// 'A'-'Z'
     ///
     Mod_27_26,
+    ///
+    /// The data characters must be '0' - '9', 'A' - 'Z' but the check
+    /// digit may also be '*' (representing the value 36).
+    ///
     Mod_37_2,
     ///
     /// This scheme works similar to Mod_11_10, but is defined to
@@ -85,6 +110,19 @@ pub enum IsoVariant {
     Mod_1271_36,
 }
 
+///
+/// The check character(s) calculated by one of the ISO 7064 variants. The
+/// `Display` implementation will render the value using the check character
+/// alphabet of the variant, including the supplementary characters `'X'` and
+/// `'*'`, and will always produce the number of characters the variant
+/// requires.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CheckCharacters {
+    variant: IsoVariant,
+    value: u16,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct CheckDigitAlgorithm {
     variant: IsoVariant,
@@ -102,12 +140,18 @@ pub const fn get_algorithm_instance(variant: IsoVariant) -> CheckDigitAlgorithm
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+const NUMERIC_ALPHABET: &[u8] = b"0123456789";
+const NUMERIC_OR_X_ALPHABET: &[u8] = b"0123456789X";
+const ALPHA_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const ALPHANUMERIC_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const ALPHANUMERIC_OR_STAR_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ*";
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
 impl Display for IsoVariant {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.name())
     }
 }
@@ -126,6 +170,61 @@ impl IsoVariant {
         }
     }
 
+    ///
+    /// The modulus *M* used by this variant; for hybrid systems the second
+    /// modulus is always *M+1*.
+    ///
+    pub const fn modulus(&self) -> u16 {
+        match self {
+            Self::Mod_11_2 => 11,
+            Self::Mod_11_10 => 10,
+            Self::Mod_27_26 => 26,
+            Self::Mod_37_2 => 37,
+            Self::Mod_37_36 => 36,
+            Self::Mod_97_10 => 97,
+            Self::Mod_661_26 => 661,
+            Self::Mod_1271_36 => 1271,
+        }
+    }
+
+    ///
+    /// The radix *r* used by this variant, for hybrid systems this is always
+    /// the same as the modulus *M*.
+    ///
+    pub const fn radix(&self) -> u16 {
+        match self {
+            Self::Mod_11_2 => 2,
+            Self::Mod_11_10 => 10,
+            Self::Mod_27_26 => 26,
+            Self::Mod_37_2 => 2,
+            Self::Mod_37_36 => 36,
+            Self::Mod_97_10 => 10,
+            Self::Mod_661_26 => 26,
+            Self::Mod_1271_36 => 36,
+        }
+    }
+
+    ///
+    /// Returns `true` if this variant is one of the hybrid systems, else it
+    /// is one of the pure systems.
+    ///
+    pub const fn is_hybrid(&self) -> bool {
+        matches!(self, Self::Mod_11_10 | Self::Mod_27_26 | Self::Mod_37_36)
+    }
+
+    const fn check_alphabet(&self) -> &'static [u8] {
+        match self {
+            Self::Mod_11_2 => NUMERIC_OR_X_ALPHABET,
+            Self::Mod_11_10 => NUMERIC_ALPHABET,
+            Self::Mod_27_26 => ALPHA_ALPHABET,
+            Self::Mod_37_2 => ALPHANUMERIC_OR_STAR_ALPHABET,
+            Self::Mod_37_36 => ALPHANUMERIC_ALPHABET,
+            Self::Mod_97_10 => NUMERIC_ALPHABET,
+            Self::Mod_661_26 => ALPHA_ALPHABET,
+            Self::Mod_1271_36 => ALPHANUMERIC_ALPHABET,
+        }
+    }
+
     const fn name(&self) -> &'static str {
         match self {
             Self::Mod_11_2 => "ISO 7064 - MOD 11-2",
@@ -142,34 +241,111 @@ impl IsoVariant {
 
 // ------------------------------------------------------------------------------------------------
 
+impl Display for CheckCharacters {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let alphabet = self.variant.check_alphabet();
+        if self.variant.check_digits() == 2 {
+            let radix = self.variant.radix();
+            write!(
+                f,
+                "{}{}",
+                alphabet[(self.value / radix) as usize] as char,
+                alphabet[(self.value % radix) as usize] as char
+            )
+        } else {
+            write!(f, "{}", alphabet[self.value as usize] as char)
+        }
+    }
+}
+
+impl CheckCharacters {
+    ///
+    /// The variant used to calculate these check characters.
+    ///
+    pub const fn variant(&self) -> IsoVariant {
+        self.variant
+    }
+
+    ///
+    /// The numeric value of the check character(s), for two character
+    /// systems this is `(high * radix) + low`.
+    ///
+    pub const fn value(&self) -> u16 {
+        self.value
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
 impl CheckDigitAlgorithm {
     pub const fn new(variant: IsoVariant) -> Self {
         Self { variant }
     }
 }
 
-impl Calculator<u8> for CheckDigitAlgorithm {
+impl Calculator<CheckCharacters> for CheckDigitAlgorithm {
     fn name(&self) -> &'static str {
         self.variant.name()
     }
 
+    fn alphabet(&self) -> &'static str {
+        match self.variant {
+            IsoVariant::Mod_11_2 => "0123456789X",
+            IsoVariant::Mod_11_10 => "0123456789",
+            IsoVariant::Mod_27_26 | IsoVariant::Mod_661_26 => "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            IsoVariant::Mod_37_2 => "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ*",
+            IsoVariant::Mod_37_36 | IsoVariant::Mod_97_10 | IsoVariant::Mod_1271_36 => {
+                "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+            }
+        }
+    }
+
+    // The pure systems detect all single substitutions and adjacent
+    // transpositions, the hybrid systems only single substitutions. For
+    // Mod 97,10 this holds for numeric input, letters are expanded to two
+    // digits and so a substitution or transposition may change the length.
+    fn detected_errors(&self) -> &'static [ErrorClass] {
+        match self.variant {
+            IsoVariant::Mod_11_2
+            | IsoVariant::Mod_37_2
+            | IsoVariant::Mod_97_10
+            | IsoVariant::Mod_661_26
+            | IsoVariant::Mod_1271_36 => &[
+                ErrorClass::SingleSubstitution,
+                ErrorClass::AdjacentTransposition,
+            ],
+            IsoVariant::Mod_11_10 | IsoVariant::Mod_27_26 | IsoVariant::Mod_37_36 => {
+                &[ErrorClass::SingleSubstitution]
+            }
+        }
+    }
+
     fn number_of_check_digit_chars(&self) -> usize {
         self.variant.check_digits()
     }
 
-    fn calculate(&self, s: &str) -> Result<u8, crate::error::CheckDigitError> {
-        match self.variant {
-            IsoVariant::Mod_11_2 => is_ascii_numeric(s)?,
-            IsoVariant::Mod_27_26 => is_ascii_alpha_upper(s)?,
+    fn calculate(&self, s: &str) -> Result<CheckCharacters, CheckDigitError> {
+        let variant = self.variant;
+        let value = match variant {
+            IsoVariant::Mod_11_2 | IsoVariant::Mod_11_10 => {
+                is_ascii_numeric(s)?;
+                values_of(s, variant, ascii_numeric_to_u8)
+            }
+            IsoVariant::Mod_27_26 | IsoVariant::Mod_661_26 => {
+                is_ascii_alpha_upper(s)?;
+                values_of(s, variant, ascii_alpha_to_u8)
+            }
+            IsoVariant::Mod_37_2 | IsoVariant::Mod_37_36 | IsoVariant::Mod_1271_36 => {
+                is_ascii_alphanumeric_upper(s)?;
+                values_of(s, variant, ascii_alphanum_to_u8)
+            }
             IsoVariant::Mod_97_10 => {
                 is_ascii_alphanumeric_upper(s)?;
-                let s = format!("{}00", string_to_numeric_string(s, ascii_alphanum_to_u8));
-                return Ok(98 - calculate_mod(&s, 97) as u8);
+                let digits = string_to_numeric_digits(s, ascii_alphanum_to_u8).chain([0, 0]);
+                98 - calculate_mod_digits(digits, 97)
             }
-            IsoVariant::Mod_661_26 => is_ascii_alpha_upper(s)?,
-            _ => is_ascii_alphanumeric_upper(s)?,
-        }
-        todo!()
+        };
+        Ok(CheckCharacters { variant, value })
     }
 }
 
@@ -177,6 +353,56 @@ impl Calculator<u8> for CheckDigitAlgorithm {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+#[inline(always)]
+fn values_of(s: &str, variant: IsoVariant, f: fn(char) -> u8) -> u16 {
+    let values = s.chars().map(f);
+    if variant.is_hybrid() {
+        hybrid_system(values, variant.modulus())
+    } else {
+        pure_system(
+            values,
+            variant.modulus(),
+            variant.radix(),
+            variant.check_digits(),
+        )
+    }
+}
+
+///
+/// The recursive method for pure systems, ISO/IEC 7064:2003 clause 7.1. For
+/// two check characters the result is split into high and low characters
+/// using the radix.
+///
+fn pure_system(
+    values: impl Iterator<Item = u8>,
+    modulus: u16,
+    radix: u16,
+    check_chars: usize,
+) -> u16 {
+    let modulus = modulus as u32;
+    let radix = radix as u32;
+    let mut p = values.fold(0, |p, a| ((p + a as u32) * radix) % modulus);
+    if check_chars == 2 {
+        p = (p * radix) % modulus;
+    }
+    ((modulus + 1 - p) % modulus) as u16
+}
+
+///
+/// The recursive method for hybrid systems, ISO/IEC 7064:2003 clause 8.1,
+/// using the moduli *M* and *M+1*.
+///
+fn hybrid_system(values: impl Iterator<Item = u8>, modulus: u16) -> u16 {
+    let p = values.fold(modulus, |p, a| {
+        let s = match (p + a as u16) % modulus {
+            0 => modulus,
+            s => s,
+        };
+        (s * 2) % (modulus + 1)
+    });
+    (modulus + 1 - p) % modulus
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -189,10 +415,179 @@ impl Calculator<u8> for CheckDigitAlgorithm {
 mod tests {
     use super::*;
 
+    fn check(variant: IsoVariant, s: &str) -> String {
+        get_algorithm_instance(variant)
+            .calculate(s)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_mod_11_2() {
+        assert_eq!(check(IsoVariant::Mod_11_2, "079"), "X");
+        assert_eq!(check(IsoVariant::Mod_11_2, "0794"), "0");
+        assert_eq!(check(IsoVariant::Mod_11_2, "000000021825009"), "7");
+        assert!(get_algorithm_instance(IsoVariant::Mod_11_2).is_valid("079X"));
+    }
+
+    #[test]
+    fn test_mod_37_2() {
+        assert_eq!(check(IsoVariant::Mod_37_2, "G123498654321"), "H");
+        assert_eq!(check(IsoVariant::Mod_37_2, "G123489654321"), "Y");
+        assert_eq!(check(IsoVariant::Mod_37_2, "0000001"), "*");
+    }
+
+    #[test]
+    fn test_mod_97_10() {
+        assert_eq!(check(IsoVariant::Mod_97_10, "794"), "44");
+        assert_eq!(check(IsoVariant::Mod_97_10, "YZ83GD8L7GG84979J5"), "16");
+    }
+
+    #[test]
+    fn test_mod_661_26() {
+        assert_eq!(check(IsoVariant::Mod_661_26, "ISOHJ"), "TC");
+        assert!(get_algorithm_instance(IsoVariant::Mod_661_26).is_valid("ISOHJTC"));
+    }
+
+    #[test]
+    fn test_mod_1271_36() {
+        assert_eq!(check(IsoVariant::Mod_1271_36, "ISO79"), "3W");
+        assert!(get_algorithm_instance(IsoVariant::Mod_1271_36).is_valid("ISO793W"));
+    }
+
+    #[test]
+    fn test_mod_11_10() {
+        assert_eq!(check(IsoVariant::Mod_11_10, "0794"), "5");
+        assert_eq!(check(IsoVariant::Mod_11_10, "79462"), "3");
+    }
+
+    #[test]
+    fn test_mod_27_26() {
+        assert_eq!(check(IsoVariant::Mod_27_26, "JEJLMGJ"), "S");
+    }
+
+    #[test]
+    fn test_mod_37_36() {
+        assert_eq!(check(IsoVariant::Mod_37_36, "A12425GABC1234002"), "M");
+        assert_eq!(check(IsoVariant::Mod_37_36, "G123498654321"), "0");
+    }
+
+    const NUMERIC: &str = "0123456789";
+    const ALPHA: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    // Search all strings of `length` characters from `alphabet` for a pair
+    // that differ by a single error of class `error` but share check
+    // characters.
+    fn find_undetected(
+        variant: IsoVariant,
+        alphabet: &str,
+        length: u32,
+        error: ErrorClass,
+    ) -> Option<(String, String)> {
+        let algorithm = get_algorithm_instance(variant);
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        let radix = alphabet.len();
+        for n in 0..radix.pow(length) {
+            let chars: Vec<char> = (0..length)
+                .map(|i| alphabet[(n / radix.pow(i)) % radix])
+                .collect();
+            let s: String = chars.iter().collect();
+            let check = algorithm.calculate(&s).unwrap();
+            let mut candidates: Vec<String> = Vec::new();
+            for i in 0..chars.len() {
+                match error {
+                    ErrorClass::SingleSubstitution => {
+                        for c in alphabet.iter().filter(|c| **c != chars[i]) {
+                            let mut changed = chars.clone();
+                            changed[i] = *c;
+                            candidates.push(changed.iter().collect());
+                        }
+                    }
+                    ErrorClass::AdjacentTransposition => {
+                        if i + 1 < chars.len() && chars[i] != chars[i + 1] {
+                            let mut changed = chars.clone();
+                            changed.swap(i, i + 1);
+                            candidates.push(changed.iter().collect());
+                        }
+                    }
+                }
+            }
+            if let Some(other) = candidates
+                .into_iter()
+                .find(|other| algorithm.calculate(other).unwrap() == check)
+            {
+                return Some((s, other));
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_detected_errors() {
+        for (variant, alphabet, length) in [
+            (IsoVariant::Mod_11_2, NUMERIC, 4),
+            (IsoVariant::Mod_11_10, NUMERIC, 4),
+            (IsoVariant::Mod_97_10, NUMERIC, 4),
+            (IsoVariant::Mod_27_26, ALPHA, 2),
+            (IsoVariant::Mod_661_26, ALPHA, 2),
+            (IsoVariant::Mod_37_2, ALPHANUMERIC, 2),
+            (IsoVariant::Mod_37_36, ALPHANUMERIC, 2),
+            (IsoVariant::Mod_1271_36, ALPHANUMERIC, 2),
+        ] {
+            let detected = get_algorithm_instance(variant).detected_errors();
+            for error in [
+                ErrorClass::SingleSubstitution,
+                ErrorClass::AdjacentTransposition,
+            ] {
+                let undetected = find_undetected(variant, alphabet, length, error);
+                assert_eq!(
+                    detected.contains(&error),
+                    undetected.is_none(),
+                    "{:?} {:?} {:?}",
+                    variant,
+                    error,
+                    undetected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_detected_errors_examples() {
+        assert_eq!(
+            check(IsoVariant::Mod_11_10, "12786"),
+            check(IsoVariant::Mod_11_10, "12876")
+        );
+        // Mod 97,10 only guarantees detection for numeric input.
+        assert!(find_undetected(
+            IsoVariant::Mod_97_10,
+            ALPHANUMERIC,
+            2,
+            ErrorClass::SingleSubstitution
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_invalid_alphabet() {
+        assert!(get_algorithm_instance(IsoVariant::Mod_11_2)
+            .calculate("07A")
+            .is_err());
+        assert!(get_algorithm_instance(IsoVariant::Mod_11_10)
+            .calculate("07A")
+            .is_err());
+        assert!(get_algorithm_instance(IsoVariant::Mod_661_26)
+            .calculate("IS0")
+            .is_err());
+    }
+
     #[test]
     fn test_string_to_string_a36() {
         assert_eq!(
-            &string_to_numeric_string("10BX939C5543TQA1144M999143X", ascii_alphanum_to_u8),
+            &string_to_numeric_digits("10BX939C5543TQA1144M999143X", ascii_alphanum_to_u8)
+                .map(|d| (b'0' + d) as char)
+                .collect::<String>(),
             "10113393912554329261011442299914333"
         );
     }
@@ -18,6 +18,9 @@ assert_eq!(calculator.calculate("US037833100"), Ok(5));
 
 # Features
 
+* `cusip` - Adds the `cusip` module containing an implementation of the algorithm
+  used in CUSIP numbers.
+* `damm` - Adds the `damm` module containing an implementation of the Damm Algorithm.
 * `gs1` - Adds the `gs1` module containing algorithms for various codes such as
   EAN, GTIN, GLN, and UPC.
 * `iso_7064` - Adds the `iso_7064` module containing implementations of the
@@ -25,9 +28,34 @@ assert_eq!(calculator.calculate("US037833100"), Ok(5));
 * `luhn` - Adds the `luhn` module containing an implementation of the Luhn Algorithm.
 * `sedol` - Adds the `sedol` module containing an implementation of the algorithm
   used in SEDOL numbers.
+* `verhoeff` - Adds the `verhoeff` module containing an implementation of the
+  Verhoeff Algorithm.
+* `weighted_modulus` - Adds the `weighted_modulus` module containing a generic
+  weighted sum and modulus algorithm, with variants for ISBN-10, ISSN, VIN, IMO
+  numbers, and CAS Registry Numbers.
+* `alloc` - Adds the methods that return allocated values, such as
+  `Calculator::create` and `Calculator::suggest_corrections`.
+* `std` - Adds the `CodeWithCheckDigits` trait and conversion of errors into
+  `codes_common::CodeParseError`, this implies `alloc`.
+
+Without the `std` feature this package is `no_std`, and without the `alloc`
+feature it will not allocate. All of the algorithms calculate their check
+digits incrementally, without intermediate strings or big integer types, and
+the methods `Calculator::create_into` and `Calculator::create_into_slice` will
+write a new code to a caller provided writer or buffer.
+
+```rust
+use codes_check_digits::{luhn, Calculator};
+
+let calculator = luhn::get_algorithm_instance();
+let mut buffer = [0u8; 12];
+let isin = calculator.create_into_slice("US037833100", &mut buffer).unwrap();
+assert_eq!(isin, "US0378331005");
+```
 
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
     unknown_lints,
     // ---------- Stylistic
@@ -57,7 +85,8 @@ assert_eq!(calculator.calculate("US037833100"), Ok(5));
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -68,17 +97,33 @@ assert_eq!(calculator.calculate("US037833100"), Ok(5));
     dyn_drop,
 )]
 
-use codes_common::Code;
-use error::CheckDigitError;
-use std::{borrow::Cow, fmt::Display};
+use core::fmt::{Display, Write};
+use correction::ErrorClass;
+use error::{CheckDigitError, CheckDigits};
 use tracing::trace;
 
-use crate::error::invalid_check_digit;
+use crate::error::{buffer_too_small, invalid_check_digit, invalid_length};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use correction::Correction;
+
+#[cfg(feature = "std")]
+use codes_common::Code;
+
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "std")]
 pub trait CodeWithCheckDigits: Code<String> + AsRef<str> {
     type CheckDigit: Display + PartialEq;
     type CheckDigitCalculator: Calculator<Self::CheckDigit> + Copy;
@@ -116,21 +161,84 @@ where
     ///
     fn name(&self) -> &'static str;
 
+    ///
+    /// Return the characters that may appear in a valid string, including
+    /// the check digit(s). This is used to generate candidate corrections.
+    ///
+    fn alphabet(&self) -> &'static str {
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+    }
+
+    ///
+    /// Return the classes of error that this algorithm is guaranteed to
+    /// detect, for any valid input.
+    ///
+    fn detected_errors(&self) -> &'static [ErrorClass] {
+        &[]
+    }
+
     ///
     /// Calculate a check digit for the provided string.
     ///
     fn calculate(&self, s: &str) -> Result<T, CheckDigitError>;
 
     ///
-    /// Create a new string with the original data plus check digit.
+    /// Write the original data plus check digit to the provided writer.
     ///
-    fn create(&self, s: &str) -> Result<String, CheckDigitError> {
-        Ok(format!(
+    fn create_into<W>(&self, s: &str, w: &mut W) -> Result<(), CheckDigitError>
+    where
+        Self: Sized,
+        W: Write,
+    {
+        let check = self.calculate(s)?;
+        write!(
+            w,
             "{}{:0>width$}",
             s,
-            self.calculate(s)?,
+            check,
             width = self.number_of_check_digit_chars()
-        ))
+        )
+        .map_err(|_| CheckDigitError::WriteFailed)
+    }
+
+    ///
+    /// Write the original data plus check digit to the provided buffer,
+    /// returning the part of the buffer written to as a string.
+    ///
+    fn create_into_slice<'a>(
+        &self,
+        s: &str,
+        buffer: &'a mut [u8],
+    ) -> Result<&'a str, CheckDigitError>
+    where
+        Self: Sized,
+    {
+        let mut writer = SliceWriter { buffer, len: 0 };
+        match self.create_into(s, &mut writer) {
+            Ok(()) => {
+                let SliceWriter { buffer, len } = writer;
+                // Only whole `str` values are ever written, so this is always valid.
+                Ok(core::str::from_utf8(&buffer[..len]).unwrap_or_default())
+            }
+            Err(CheckDigitError::WriteFailed) => Err(buffer_too_small(
+                s.len() + self.number_of_check_digit_chars(),
+                writer.buffer.len(),
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    ///
+    /// Create a new string with the original data plus check digit.
+    ///
+    #[cfg(feature = "alloc")]
+    fn create(&self, s: &str) -> Result<String, CheckDigitError>
+    where
+        Self: Sized,
+    {
+        let mut result = String::with_capacity(s.len() + self.number_of_check_digit_chars());
+        self.create_into(s, &mut result)?;
+        Ok(result)
     }
 
     ///
@@ -149,12 +257,23 @@ where
             "Validating check digits for input {:?}",
             s
         );
+        if s.len() < self.number_of_check_digit_chars() {
+            return Err(invalid_length(
+                self.number_of_check_digit_chars()..=usize::MAX,
+                s.len(),
+            ));
+        }
         let check_digit_index = s.len() - self.number_of_check_digit_chars();
+        if !s.is_char_boundary(check_digit_index) {
+            return Err(invalid_length(
+                check_digit_index..=check_digit_index,
+                s.len(),
+            ));
+        }
         let check = self.calculate(&s[0..check_digit_index])?;
-        if s[check_digit_index..] == check.to_string() {
-            Ok(())
-        } else {
-            Err(invalid_check_digit(&s[check_digit_index..], check))
+        match CheckDigits::try_from_display(&check) {
+            Some(check_digits) if check_digits == s[check_digit_index..] => Ok(()),
+            _ => Err(invalid_check_digit(&s[check_digit_index..], check)),
         }
     }
 
@@ -170,12 +289,31 @@ where
     {
         self.validate(s).is_ok()
     }
+
+    ///
+    /// Return a ranked list of valid strings that differ from the provided,
+    /// invalid, string by a single substitution or an adjacent
+    /// transposition. If the string is already valid the list is empty.
+    ///
+    /// Substitutions of commonly confused characters, such as `'0'` and
+    /// `'O'`, are ranked first followed by transpositions and then any other
+    /// substitutions.
+    ///
+    #[cfg(feature = "alloc")]
+    fn suggest_corrections<S>(&self, s: S) -> Vec<Correction>
+    where
+        Self: Sized,
+        S: AsRef<str>,
+    {
+        correction::suggest_corrections(self, s.as_ref())
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
 // Public Macros
 // ------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! check_digits_impl {
     ($type_name:ty, $error_type:ty, $algorithm_type:ty, $check_digit_type:ty, $algorithm_init:expr) => {
@@ -201,10 +339,32 @@ macro_rules! check_digits_impl {
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buffer.len() {
+            Err(core::fmt::Error)
+        } else {
+            self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -212,6 +372,14 @@ macro_rules! check_digits_impl {
 #[doc(hidden)]
 mod common;
 
+pub mod correction;
+
+#[cfg(feature = "cusip")]
+pub mod cusip;
+
+#[cfg(feature = "damm")]
+pub mod damm;
+
 pub mod error;
 
 #[cfg(feature = "gs1")]
@@ -225,3 +393,9 @@ pub mod luhn;
 
 #[cfg(feature = "sedol")]
 pub mod sedol;
+
+#[cfg(feature = "verhoeff")]
+pub mod verhoeff;
+
+#[cfg(feature = "weighted_modulus")]
+pub mod weighted_modulus;
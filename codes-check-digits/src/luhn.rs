@@ -15,7 +15,11 @@ assert_eq!(calculator.calculate("US037833100"), Ok(5));
 
 */
 
-use crate::{common::is_ascii_alphanumeric_upper, error::CheckDigitError, Calculator};
+use crate::{
+    common::{ascii_alphanum_to_u8, is_ascii_alphanumeric_upper, string_to_numeric_digits},
+    error::CheckDigitError,
+    Calculator,
+};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -55,15 +59,13 @@ impl Calculator<u8> for CheckDigitAlgorithm {
 
     fn calculate(&self, s: &str) -> Result<u8, CheckDigitError> {
         is_ascii_alphanumeric_upper(s)?;
-        let sum: u8 = s
-            .chars()
-            .flat_map(luhn_alphanum_to_vec)
+        // the sum is reduced on each step so that long inputs cannot overflow.
+        let sum = string_to_numeric_digits(s, ascii_alphanum_to_u8)
             .rev()
             .enumerate()
-            .map(|(i, n)| luhn_double_odd(i & 1 == 1, n))
-            .sum();
+            .fold(0, |sum, (i, n)| (sum + luhn_double_odd(i & 1 == 1, n)) % 10);
 
-        Ok((10 - (sum % 10)) % 10)
+        Ok((10 - sum) % 10)
     }
 }
 
@@ -88,19 +90,6 @@ fn luhn_double_odd(is_odd: bool, d: u8) -> u8 {
     }
 }
 
-#[inline(always)]
-fn luhn_alphanum_to_vec(c: char) -> Vec<u8> {
-    let d = c as u8;
-    match d {
-        b'0'..=b'9' => vec![d - b'0'],
-        b'A'..=b'Z' => {
-            let d = d - 55;
-            vec![d / 10, d % 10]
-        }
-        _ => panic!(),
-    }
-}
-
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
@@ -116,4 +105,13 @@ mod tests {
         assert!(calculator.validate("US0378331005").is_ok());
         assert!(calculator.validate("037833100").is_ok());
     }
+
+    #[test]
+    fn test_long_input() {
+        let calculator: CheckDigitAlgorithm = Default::default();
+        // every digit contributes 9, doubled or not, so the sum is 360,009.
+        let s = "9".repeat(40_001);
+        assert_eq!(calculator.calculate(&s), Ok(1));
+        assert!(calculator.validate(format!("{}1", s)).is_ok());
+    }
 }
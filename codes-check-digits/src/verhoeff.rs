@@ -0,0 +1,131 @@
+/*!
+The type [CheckDigitAlgorithm] provides an implementation of the
+[Verhoeff Algorithm](https://en.wikipedia.org/wiki/Verhoeff_algorithm).
+
+# Example
+
+```rust
+use codes_check_digits::{verhoeff, Calculator};
+
+let calculator = verhoeff::get_algorithm_instance();
+assert!(calculator.is_valid("2363"));
+assert!(calculator.validate("2363").is_ok());
+assert_eq!(calculator.calculate("236"), Ok(3));
+```
+
+*/
+
+use crate::{
+    common::{ascii_numeric_to_u8, is_ascii_numeric},
+    correction::ErrorClass,
+    error::CheckDigitError,
+    Calculator,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The Verhoeff algorithm uses the dihedral group *D5* to detect all single
+/// digit errors and all transposition errors of adjacent digits. It is used
+/// in a number of national identity schemes such as the Indian Aadhaar
+/// number.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckDigitAlgorithm {}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+const SHARED_INSTANCE: CheckDigitAlgorithm = CheckDigitAlgorithm {};
+
+pub const fn get_algorithm_instance() -> &'static CheckDigitAlgorithm {
+    &SHARED_INSTANCE
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// The multiplication table of the dihedral group D5.
+const MULTIPLICATION: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+// The permutation applied to a digit based on its position.
+const PERMUTATION: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 8, 7, 6, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const INVERSE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+impl Calculator<u8> for CheckDigitAlgorithm {
+    fn name(&self) -> &'static str {
+        "Verhoeff Algorithm"
+    }
+
+    fn alphabet(&self) -> &'static str {
+        "0123456789"
+    }
+
+    fn detected_errors(&self) -> &'static [ErrorClass] {
+        &[
+            ErrorClass::SingleSubstitution,
+            ErrorClass::AdjacentTransposition,
+        ]
+    }
+
+    fn calculate(&self, s: &str) -> Result<u8, CheckDigitError> {
+        is_ascii_numeric(s)?;
+        let check = s
+            .chars()
+            .rev()
+            .map(ascii_numeric_to_u8)
+            .enumerate()
+            .fold(0, |c, (i, d)| {
+                MULTIPLICATION[c as usize][PERMUTATION[(i + 1) % 8][d as usize] as usize]
+            });
+
+        Ok(INVERSE[check as usize])
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::verhoeff::CheckDigitAlgorithm;
+    use crate::Calculator;
+
+    #[test]
+    fn test_check_digits() {
+        let calculator = CheckDigitAlgorithm::default();
+        assert_eq!(calculator.calculate("236"), Ok(3));
+        assert_eq!(calculator.calculate("12345"), Ok(1));
+        assert_eq!(calculator.calculate("23412341234"), Ok(6));
+        assert!(calculator.is_valid("2363"));
+        // Adjacent transposition
+        assert!(!calculator.is_valid("3263"));
+        assert!(calculator.calculate("23A").is_err());
+    }
+}
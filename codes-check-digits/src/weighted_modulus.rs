@@ -0,0 +1,479 @@
+/*!
+The type [CheckDigitAlgorithm] provides a generic weighted modulus
+calculation, where each character is mapped to a value, multiplied by a
+positional weight, and the sum is reduced by a modulus. The enumeration
+[WeightedVariant] provides the parameters for a number of common codes.
+
+# Example
+
+```rust
+use codes_check_digits::{weighted_modulus, Calculator};
+
+let calculator = weighted_modulus::get_algorithm_instance(weighted_modulus::WeightedVariant::Isbn10);
+assert!(calculator.is_valid("0306406152"));
+assert_eq!(calculator.calculate("080442957"), Ok('X'));
+
+let calculator = weighted_modulus::get_algorithm_instance(weighted_modulus::WeightedVariant::Vin);
+assert!(calculator.is_valid("1M8GDM9AXKP042788"));
+```
+
+A custom algorithm can be constructed directly, the following is the same as
+the `Imo` variant.
+
+```rust
+use codes_check_digits::{weighted_modulus::{self, CheckDigitAlgorithm, Weights}, Calculator};
+
+const IMO: CheckDigitAlgorithm = CheckDigitAlgorithm::new(
+    "IMO Number",
+    Weights::Fixed(&[7, 6, 5, 4, 3, 2]),
+    10,
+    weighted_modulus::numeric_value,
+);
+assert_eq!(IMO.create("907472"), Ok("9074729".to_string()));
+```
+
+*/
+
+use crate::{
+    correction::ErrorClass,
+    error::{invalid_alphabet, invalid_check_digit, invalid_length, CheckDigitError},
+    Calculator,
+};
+use core::fmt::{Display, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// This enumeration denotes the type of code to be validated.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WeightedVariant {
+    /// International Standard Book Number, the 10 digit form used before 2007.
+    Isbn10,
+    /// International Standard Serial Number.
+    Issn,
+    /// Vehicle Identification Number, as used in North America, where the
+    /// check digit is the 9th of 17 characters.
+    Vin,
+    /// International Maritime Organization ship identification number, this
+    /// does not include the `"IMO"` prefix.
+    Imo,
+    /// Chemical Abstracts Service (CAS) Registry Number, this does not include
+    /// the hyphen separators.
+    CasRegistry,
+}
+
+///
+/// The weights applied to each character of the input.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weights {
+    /// One weight per input character, from left to right, this also
+    /// requires that the input is the same length as the weights.
+    Fixed(&'static [u16]),
+    /// The weights `1, 2, 3, ...` applied from right to left, the input may
+    /// be of any length.
+    Ascending,
+}
+
+///
+/// A function that returns the value of a character in the input, or `None`
+/// if the character is not valid.
+///
+pub type CharacterMap = fn(char) -> Option<u16>;
+
+///
+/// Validate codes using a weighted sum and a modulus.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct CheckDigitAlgorithm {
+    name: &'static str,
+    weights: Weights,
+    modulus: u16,
+    character_map: CharacterMap,
+    complement: bool,
+    check_digit_index: Option<usize>,
+    detected_errors: &'static [ErrorClass],
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub const fn get_algorithm_instance(variant: WeightedVariant) -> CheckDigitAlgorithm {
+    variant.algorithm()
+}
+
+///
+/// A [CharacterMap] for the characters `'0'..='9'`.
+///
+pub fn numeric_value(c: char) -> Option<u16> {
+    c.to_digit(10).map(|d| d as u16)
+}
+
+///
+/// A [CharacterMap] for the transliteration of characters in a VIN, the
+/// letters `'I'`, `'O'`, and `'Q'` are not valid.
+///
+pub fn vin_value(c: char) -> Option<u16> {
+    match c {
+        '0'..='9' => numeric_value(c),
+        'A'..='H' => Some(c as u16 - 'A' as u16 + 1),
+        'J'..='N' => Some(c as u16 - 'J' as u16 + 1),
+        'P' => Some(7),
+        'R' => Some(9),
+        'S'..='Z' => Some(c as u16 - 'S' as u16 + 2),
+        _ => None,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const CHECK_ALPHABET: &[u8] = b"0123456789X";
+
+impl Display for WeightedVariant {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl WeightedVariant {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Isbn10 => "ISBN-10",
+            Self::Issn => "ISSN",
+            Self::Vin => "VIN",
+            Self::Imo => "IMO Number",
+            Self::CasRegistry => "CAS Registry Number",
+        }
+    }
+
+    const fn algorithm(&self) -> CheckDigitAlgorithm {
+        match self {
+            Self::Isbn10 => CheckDigitAlgorithm::new(
+                self.name(),
+                Weights::Fixed(&[10, 9, 8, 7, 6, 5, 4, 3, 2]),
+                11,
+                numeric_value,
+            )
+            .complement()
+            .detecting(&[
+                ErrorClass::SingleSubstitution,
+                ErrorClass::AdjacentTransposition,
+            ]),
+            Self::Issn => CheckDigitAlgorithm::new(
+                self.name(),
+                Weights::Fixed(&[8, 7, 6, 5, 4, 3, 2]),
+                11,
+                numeric_value,
+            )
+            .complement()
+            .detecting(&[
+                ErrorClass::SingleSubstitution,
+                ErrorClass::AdjacentTransposition,
+            ]),
+            Self::Vin => CheckDigitAlgorithm::new(
+                self.name(),
+                Weights::Fixed(&[8, 7, 6, 5, 4, 3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2]),
+                11,
+                vin_value,
+            )
+            .check_digit_at(8),
+            Self::Imo => CheckDigitAlgorithm::new(
+                self.name(),
+                Weights::Fixed(&[7, 6, 5, 4, 3, 2]),
+                10,
+                numeric_value,
+            ),
+            Self::CasRegistry => {
+                CheckDigitAlgorithm::new(self.name(), Weights::Ascending, 10, numeric_value)
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<WeightedVariant> for CheckDigitAlgorithm {
+    fn from(variant: WeightedVariant) -> Self {
+        variant.algorithm()
+    }
+}
+
+impl CheckDigitAlgorithm {
+    ///
+    /// Create a new algorithm where the check digit is `sum % modulus`. The
+    /// modulus must be no greater than 11, a check value of 10 is
+    /// represented by the character `'X'`.
+    ///
+    /// # Panics
+    ///
+    /// If `modulus` is not in the range `2..=11`; in a `const` this is a
+    /// compile time error, otherwise see [CheckDigitAlgorithm::try_new].
+    ///
+    pub const fn new(
+        name: &'static str,
+        weights: Weights,
+        modulus: u16,
+        character_map: CharacterMap,
+    ) -> Self {
+        match Self::try_new(name, weights, modulus, character_map) {
+            Some(algorithm) => algorithm,
+            None => panic!("modulus must be in the range 2..=11"),
+        }
+    }
+
+    ///
+    /// Create a new algorithm, as [CheckDigitAlgorithm::new] does, returning
+    /// `None` if `modulus` is not in the range `2..=11`.
+    ///
+    pub const fn try_new(
+        name: &'static str,
+        weights: Weights,
+        modulus: u16,
+        character_map: CharacterMap,
+    ) -> Option<Self> {
+        if modulus > 1 && modulus <= 11 {
+            Some(Self {
+                name,
+                weights,
+                modulus,
+                character_map,
+                complement: false,
+                check_digit_index: None,
+                detected_errors: &[],
+            })
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Return a copy of this algorithm where the check digit is
+    /// `(modulus - (sum % modulus)) % modulus`.
+    ///
+    pub const fn complement(self) -> Self {
+        Self {
+            complement: true,
+            ..self
+        }
+    }
+
+    ///
+    /// Return a copy of this algorithm where the check digit is at the
+    /// provided index in the code rather than the last character.
+    ///
+    pub const fn check_digit_at(self, index: usize) -> Self {
+        Self {
+            check_digit_index: Some(index),
+            ..self
+        }
+    }
+
+    ///
+    /// Return a copy of this algorithm that reports it is guaranteed to
+    /// detect the provided classes of error. This is not verified, it
+    /// depends on the combination of weights, modulus, and character map.
+    ///
+    pub const fn detecting(self, detected_errors: &'static [ErrorClass]) -> Self {
+        Self {
+            detected_errors,
+            ..self
+        }
+    }
+}
+
+impl Calculator<char> for CheckDigitAlgorithm {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn detected_errors(&self) -> &'static [ErrorClass] {
+        self.detected_errors
+    }
+
+    fn calculate(&self, s: &str) -> Result<char, CheckDigitError> {
+        self.calculate_chars(s.chars(), s.chars().count())
+    }
+
+    fn create_into<W>(&self, s: &str, w: &mut W) -> Result<(), CheckDigitError>
+    where
+        Self: Sized,
+        W: Write,
+    {
+        let check = self.calculate(s)?;
+        match self.check_digit_index {
+            Some(index) if index <= s.len() && s.is_char_boundary(index) => {
+                write!(w, "{}{}{}", &s[..index], check, &s[index..])
+            }
+            Some(index) => return Err(invalid_length(index..=index, s.len())),
+            None => write!(w, "{}{}", s, check),
+        }
+        .map_err(|_| CheckDigitError::WriteFailed)
+    }
+
+    fn validate<S>(&self, s: S) -> Result<(), CheckDigitError>
+    where
+        Self: Sized,
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        if s.is_empty() {
+            return Err(invalid_length(1..=usize::MAX, 0));
+        }
+        let index = self.check_digit_index.unwrap_or(s.len() - 1);
+        if index >= s.len() || !s.is_char_boundary(index) || !s.is_char_boundary(index + 1) {
+            return Err(invalid_length(index + 1..=usize::MAX, s.len()));
+        }
+        let data = s[..index].chars().chain(s[(index + 1)..].chars());
+        let check = self.calculate_chars(data, s.chars().count() - 1)?;
+        let mut buffer = [0; 4];
+        if s[index..(index + 1)] == *check.encode_utf8(&mut buffer) {
+            Ok(())
+        } else {
+            Err(invalid_check_digit(&s[index..(index + 1)], check))
+        }
+    }
+}
+
+impl CheckDigitAlgorithm {
+    fn calculate_chars<I>(&self, chars: I, length: usize) -> Result<char, CheckDigitError>
+    where
+        I: DoubleEndedIterator<Item = char>,
+    {
+        // the sum is reduced on each step so that long inputs cannot overflow.
+        let modulus = self.modulus as u32;
+        let sum: u32 = match self.weights {
+            Weights::Fixed(weights) => {
+                if length != weights.len() {
+                    return Err(invalid_length(weights.len()..=weights.len(), length));
+                }
+                chars.zip(weights).try_fold(0, |sum, (c, w)| {
+                    self.value_of(c).map(|v| (sum + v * *w as u32) % modulus)
+                })?
+            }
+            Weights::Ascending => chars.rev().enumerate().try_fold(0, |sum, (i, c)| {
+                let weight = (i % modulus as usize) as u32 + 1;
+                self.value_of(c).map(|v| (sum + v * weight) % modulus)
+            })?,
+        };
+        let check = if self.complement {
+            (modulus - (sum % modulus)) % modulus
+        } else {
+            sum % modulus
+        };
+
+        Ok(CHECK_ALPHABET[check as usize] as char)
+    }
+
+    #[inline(always)]
+    fn value_of(&self, c: char) -> Result<u32, CheckDigitError> {
+        (self.character_map)(c)
+            .map(|v| v as u32)
+            .ok_or_else(|| invalid_alphabet(self.name))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::weighted_modulus::{
+        get_algorithm_instance, numeric_value, CheckDigitAlgorithm, WeightedVariant, Weights,
+    };
+    use crate::Calculator;
+
+    #[test]
+    fn test_isbn_10() {
+        let calculator = get_algorithm_instance(WeightedVariant::Isbn10);
+        assert_eq!(calculator.calculate("030640615"), Ok('2'));
+        assert_eq!(calculator.calculate("080442957"), Ok('X'));
+        assert!(calculator.is_valid("080442957X"));
+        assert!(!calculator.is_valid("0306406153"));
+        assert!(calculator.calculate("03064061").is_err());
+    }
+
+    #[test]
+    fn test_issn() {
+        let calculator = get_algorithm_instance(WeightedVariant::Issn);
+        assert_eq!(calculator.calculate("0378595"), Ok('5'));
+        assert!(calculator.is_valid("03785955"));
+    }
+
+    #[test]
+    fn test_vin() {
+        let calculator = get_algorithm_instance(WeightedVariant::Vin);
+        assert!(calculator.is_valid("1M8GDM9AXKP042788"));
+        assert!(calculator.is_valid("11111111111111111"));
+        assert!(!calculator.is_valid("1M8GDM9A1KP042788"));
+        assert_eq!(
+            calculator.create("1M8GDM9AKP042788"),
+            Ok("1M8GDM9AXKP042788".to_string())
+        );
+        // 'I', 'O', and 'Q' are not valid characters.
+        assert!(!calculator.is_valid("1M8GDM9AXKP04278O"));
+    }
+
+    #[test]
+    fn test_imo() {
+        let calculator = get_algorithm_instance(WeightedVariant::Imo);
+        assert_eq!(calculator.calculate("907472"), Ok('9'));
+        assert!(calculator.is_valid("9074729"));
+    }
+
+    #[test]
+    fn test_cas_registry() {
+        let calculator = get_algorithm_instance(WeightedVariant::CasRegistry);
+        // Water, 7732-18-5
+        assert_eq!(calculator.calculate("773218"), Ok('5'));
+        assert!(calculator.is_valid("7732185"));
+        // Benzene, 71-43-2
+        assert!(calculator.is_valid("71432"));
+    }
+
+    #[test]
+    fn test_long_input() {
+        let calculator = get_algorithm_instance(WeightedVariant::CasRegistry);
+        let s = "9".repeat(40_000);
+        let expected = (1..=40_000u64).map(|w| 9 * w).sum::<u64>() % 10;
+        assert_eq!(
+            calculator.calculate(&s),
+            Ok(char::from_digit(expected as u32, 10).unwrap())
+        );
+        assert!(calculator.is_valid(format!("{}{}", s, expected)));
+
+        let calculator = CheckDigitAlgorithm::new("M11", Weights::Ascending, 11, numeric_value);
+        let expected = (1..=40_000u64).map(|w| 9 * w).sum::<u64>() % 11;
+        assert_eq!(
+            calculator.calculate(&s),
+            Ok(if expected == 10 {
+                'X'
+            } else {
+                char::from_digit(expected as u32, 10).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(
+            CheckDigitAlgorithm::try_new("M11", Weights::Ascending, 11, numeric_value).is_some()
+        );
+        assert!(CheckDigitAlgorithm::try_new("M1", Weights::Ascending, 1, numeric_value).is_none());
+        assert!(
+            CheckDigitAlgorithm::try_new("M12", Weights::Ascending, 12, numeric_value).is_none()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_invalid_modulus() {
+        let _ = CheckDigitAlgorithm::new("M12", Weights::Ascending, 12, numeric_value);
+    }
+}
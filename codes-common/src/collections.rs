@@ -0,0 +1,1069 @@
+/*!
+Provides compact set and map collections for code types that implement the
+[CodeEnum] trait.
+
+Both collections are indexed by the ordinal of each code, a [CodeSet] is a
+bit set with one bit per code, and a [CodeMap] is a dense array with one slot
+per code. For a closed set of a few hundred values, such as country or currency
+codes, these are considerably smaller and faster than the equivalent
+`HashSet` and `HashMap`.
+
+# Example
+
+```rust
+use codes_common::collections::{CodeMap, CodeSet};
+use codes_common::{code_enum, code_map, code_set, CodeEnum};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Fruit {
+    Apple,
+    Banana,
+    Cherry,
+}
+
+const ALL_FRUIT: [Fruit; 3] = [Fruit::Apple, Fruit::Banana, Fruit::Cherry];
+
+code_enum!(Fruit, ALL_FRUIT);
+
+static RED_FRUIT: CodeSet<Fruit> = code_set![Fruit::Apple, Fruit::Cherry];
+
+static CALORIES: CodeMap<Fruit, u16> = code_map! {
+    Fruit::Apple => 52,
+    Fruit::Banana => 89,
+};
+
+assert!(RED_FRUIT.contains(&Fruit::Cherry));
+assert!(!RED_FRUIT.contains(&Fruit::Banana));
+assert_eq!(RED_FRUIT.complement().iter().collect::<Vec<_>>(), vec![Fruit::Banana]);
+
+assert_eq!(CALORIES.get(&Fruit::Banana), Some(&89));
+assert_eq!(CALORIES.get(&Fruit::Cherry), None);
+
+let mut yellow_fruit: CodeSet<Fruit> = CodeSet::new();
+yellow_fruit.insert(Fruit::Banana);
+assert!(RED_FRUIT.is_disjoint(&yellow_fruit));
+assert_eq!((&RED_FRUIT | &yellow_fruit).len(), 3);
+```
+
+# Constant Values
+
+The [code_set] and [code_map] macros create values in a constant context, and
+so may be used to initialize `static` or `const` values. These macros require
+that the code type is a field-less enumeration, as the ordinal is taken from
+the discriminant of each variant, and values in a map must be `Copy`. Both
+collections may also be created from an iterator at runtime for any type
+implementing [CodeEnum].
+
+# Serialization
+
+When the `serde` feature is enabled a [CodeSet] is serialized as a list of code
+strings, using `Display`, and a [CodeMap] as a map with code string keys.
+Deserialization parses the code strings using `FromStr`, and so these only
+round-trip for code types where `FromStr` accepts the strings written by
+`Display`, as is the case for all types implementing `CodeEnum` in the
+`codes-*` packages.
+
+*/
+
+use crate::CodeEnum;
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A set of codes, stored as a bit set indexed by the ordinal of each code.
+///
+#[derive(Clone)]
+pub struct CodeSet<T>
+where
+    T: CodeEnum,
+{
+    words: Cow<'static, [u64]>,
+    code_type: PhantomData<T>,
+}
+
+///
+/// An iterator over the codes in a [CodeSet], in ordinal order.
+///
+#[derive(Debug)]
+pub struct Iter<'a, T>
+where
+    T: CodeEnum,
+{
+    words: &'a [u64],
+    index: usize,
+    current: u64,
+    code_type: PhantomData<T>,
+}
+
+///
+/// A map from codes to values, stored as a dense array indexed by the ordinal
+/// of each code.
+///
+#[derive(Clone)]
+pub struct CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + 'static,
+{
+    values: Cow<'static, [Option<V>]>,
+    code_type: PhantomData<T>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Create a [CodeSet](crate::collections::CodeSet) in a constant context from
+/// a list of variants of a field-less code enumeration.
+///
+#[macro_export]
+macro_rules! code_set {
+    () => {
+        $crate::collections::CodeSet::new()
+    };
+    ($($code:expr),+ $(,)?) => {
+        $crate::collections::CodeSet::from_static_words(&const {
+            $crate::collections::bits_for::<
+                { $crate::collections::words_for(&[$($code as usize),+]) },
+            >(&[$($code as usize),+])
+        })
+    };
+}
+
+///
+/// Create a [CodeMap](crate::collections::CodeMap) in a constant context from
+/// a list of `code => value` pairs, where the codes are variants of a
+/// field-less code enumeration.
+///
+#[macro_export]
+macro_rules! code_map {
+    () => {
+        $crate::collections::CodeMap::new()
+    };
+    ($($code:expr => $value:expr),+ $(,)?) => {
+        $crate::collections::CodeMap::from_static_values(&const {
+            $crate::collections::values_for::<
+                _,
+                { [$($code as usize),+].len() },
+                { $crate::collections::slots_for(&[$($code as usize),+]) },
+            >([$($code as usize),+], [$($value),+])
+        })
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+#[doc(hidden)]
+pub const fn slots_for(ordinals: &[usize]) -> usize {
+    let mut slots = 0;
+    let mut i = 0;
+    while i < ordinals.len() {
+        if ordinals[i] >= slots {
+            slots = ordinals[i] + 1;
+        }
+        i += 1;
+    }
+    slots
+}
+
+#[doc(hidden)]
+pub const fn words_for(ordinals: &[usize]) -> usize {
+    slots_for(ordinals).div_ceil(u64::BITS as usize)
+}
+
+#[doc(hidden)]
+pub const fn bits_for<const W: usize>(ordinals: &[usize]) -> [u64; W] {
+    let mut words = [0; W];
+    let mut i = 0;
+    while i < ordinals.len() {
+        let (word, bit) = word_and_bit(ordinals[i]);
+        words[word] |= bit;
+        i += 1;
+    }
+    words
+}
+
+#[doc(hidden)]
+pub const fn values_for<V, const K: usize, const N: usize>(
+    ordinals: [usize; K],
+    values: [V; K],
+) -> [Option<V>; N]
+where
+    V: Copy,
+{
+    let mut result = [None; N];
+    let mut i = 0;
+    while i < K {
+        assert!(result[ordinals[i]].is_none(), "duplicate code in code_map");
+        result[ordinals[i]] = Some(values[i]);
+        i += 1;
+    }
+    result
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<T> Default for CodeSet<T>
+where
+    T: CodeEnum,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for CodeSet<T>
+where
+    T: CodeEnum + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> PartialEq for CodeSet<T>
+where
+    T: CodeEnum,
+{
+    fn eq(&self, other: &Self) -> bool {
+        trim_words(&self.words) == trim_words(&other.words)
+    }
+}
+
+impl<T> Eq for CodeSet<T> where T: CodeEnum {}
+
+impl<T> Hash for CodeSet<T>
+where
+    T: CodeEnum,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        trim_words(&self.words).hash(state)
+    }
+}
+
+impl<T> FromIterator<T> for CodeSet<T>
+where
+    T: CodeEnum,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> Extend<T> for CodeSet<T>
+where
+    T: CodeEnum,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for code in iter {
+            self.insert(code);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CodeSet<T>
+where
+    T: CodeEnum,
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> BitOr for &CodeSet<T>
+where
+    T: CodeEnum,
+{
+    type Output = CodeSet<T>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<T> BitAnd for &CodeSet<T>
+where
+    T: CodeEnum,
+{
+    type Output = CodeSet<T>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl<T> BitXor for &CodeSet<T>
+where
+    T: CodeEnum,
+{
+    type Output = CodeSet<T>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl<T> Sub for &CodeSet<T>
+where
+    T: CodeEnum,
+{
+    type Output = CodeSet<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl<T> CodeSet<T>
+where
+    T: CodeEnum,
+{
+    ///
+    /// Create a new, empty, set.
+    ///
+    pub const fn new() -> Self {
+        Self::from_static_words(&[])
+    }
+
+    ///
+    /// Create a new set containing all codes of type `T`.
+    ///
+    pub fn all() -> Self {
+        T::all().iter().copied().collect()
+    }
+
+    #[doc(hidden)]
+    pub const fn from_static_words(words: &'static [u64]) -> Self {
+        Self {
+            words: Cow::Borrowed(words),
+            code_type: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the number of codes in the set.
+    ///
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    ///
+    /// Returns `true` if the set contains no codes, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    ///
+    /// Returns `true` if the set contains `code`, else `false`.
+    ///
+    pub fn contains(&self, code: &T) -> bool {
+        let (word, bit) = word_and_bit(code.ordinal());
+        self.words
+            .get(word)
+            .map(|value| value & bit != 0)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Adds `code` to the set, returning `true` if it was not already
+    /// present.
+    ///
+    pub fn insert(&mut self, code: T) -> bool {
+        let (word, bit) = word_and_bit(code.ordinal());
+        if word >= self.words.len() {
+            self.words.to_mut().resize(word + 1, 0);
+        }
+        let inserted = self.words[word] & bit == 0;
+        if inserted {
+            self.words.to_mut()[word] |= bit;
+        }
+        inserted
+    }
+
+    ///
+    /// Removes `code` from the set, returning `true` if it was present.
+    ///
+    pub fn remove(&mut self, code: &T) -> bool {
+        let removed = self.contains(code);
+        if removed {
+            let (word, bit) = word_and_bit(code.ordinal());
+            self.words.to_mut()[word] &= !bit;
+        }
+        removed
+    }
+
+    ///
+    /// Removes all codes from the set.
+    ///
+    pub fn clear(&mut self) {
+        self.words = Cow::Borrowed(&[]);
+    }
+
+    ///
+    /// Returns an iterator over the codes in the set, in ordinal order.
+    ///
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            words: &self.words,
+            index: 0,
+            current: 0,
+            code_type: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns a new set containing the codes in either `self` or `other`.
+    ///
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |lhs, rhs| lhs | rhs)
+    }
+
+    ///
+    /// Returns a new set containing the codes in both `self` and `other`.
+    ///
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |lhs, rhs| lhs & rhs)
+    }
+
+    ///
+    /// Returns a new set containing the codes in `self` but not in `other`.
+    ///
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |lhs, rhs| lhs & !rhs)
+    }
+
+    ///
+    /// Returns a new set containing the codes in either `self` or `other`,
+    /// but not in both.
+    ///
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |lhs, rhs| lhs ^ rhs)
+    }
+
+    ///
+    /// Returns a new set containing all the codes of type `T` *not* in
+    /// `self`.
+    ///
+    pub fn complement(&self) -> Self {
+        Self::all().difference(self)
+    }
+
+    ///
+    /// Returns `true` if every code in `self` is also in `other`.
+    ///
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    ///
+    /// Returns `true` if every code in `other` is also in `self`.
+    ///
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` have no codes in common.
+    ///
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    fn combine<F>(&self, other: &Self, op: F) -> Self
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let len = self.words.len().max(other.words.len());
+        Self {
+            words: Cow::Owned(
+                (0..len)
+                    .map(|i| {
+                        op(
+                            self.words.get(i).copied().unwrap_or_default(),
+                            other.words.get(i).copied().unwrap_or_default(),
+                        )
+                    })
+                    .collect(),
+            ),
+            code_type: PhantomData,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T> Iterator for Iter<'_, T>
+where
+    T: CodeEnum,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.current = *self.words.get(self.index)?;
+            self.index += 1;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        T::from_ordinal((self.index - 1) * u64::BITS as usize + bit)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T, V> Default for CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V> Debug for CodeMap<T, V>
+where
+    T: CodeEnum + Debug,
+    V: Clone + Debug + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, V> PartialEq for CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        trim_values(&self.values) == trim_values(&other.values)
+    }
+}
+
+impl<T, V> Eq for CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + Eq + 'static,
+{
+}
+
+impl<T, V> FromIterator<(T, V)> for CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = (T, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<T, V> Extend<(T, V)> for CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + 'static,
+{
+    fn extend<I: IntoIterator<Item = (T, V)>>(&mut self, iter: I) {
+        for (code, value) in iter {
+            self.insert(code, value);
+        }
+    }
+}
+
+impl<T, V> CodeMap<T, V>
+where
+    T: CodeEnum,
+    V: Clone + 'static,
+{
+    ///
+    /// Create a new, empty, map.
+    ///
+    pub const fn new() -> Self {
+        Self::from_static_values(&[])
+    }
+
+    #[doc(hidden)]
+    pub const fn from_static_values(values: &'static [Option<V>]) -> Self {
+        Self {
+            values: Cow::Borrowed(values),
+            code_type: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the number of entries in the map.
+    ///
+    pub fn len(&self) -> usize {
+        self.values.iter().filter(|value| value.is_some()).count()
+    }
+
+    ///
+    /// Returns `true` if the map contains no entries, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(Option::is_none)
+    }
+
+    ///
+    /// Returns `true` if the map contains a value for `code`, else `false`.
+    ///
+    pub fn contains_key(&self, code: &T) -> bool {
+        self.get(code).is_some()
+    }
+
+    ///
+    /// Returns a reference to the value for `code`, if present.
+    ///
+    pub fn get(&self, code: &T) -> Option<&V> {
+        self.values
+            .get(code.ordinal())
+            .and_then(|value| value.as_ref())
+    }
+
+    ///
+    /// Returns a mutable reference to the value for `code`, if present.
+    ///
+    pub fn get_mut(&mut self, code: &T) -> Option<&mut V> {
+        if self.contains_key(code) {
+            self.values.to_mut()[code.ordinal()].as_mut()
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Sets the value for `code`, returning the previous value if one was
+    /// present.
+    ///
+    pub fn insert(&mut self, code: T, value: V) -> Option<V> {
+        let ordinal = code.ordinal();
+        let values = self.values.to_mut();
+        if ordinal >= values.len() {
+            values.resize(ordinal + 1, None);
+        }
+        values[ordinal].replace(value)
+    }
+
+    ///
+    /// Removes the value for `code`, returning it if one was present.
+    ///
+    pub fn remove(&mut self, code: &T) -> Option<V> {
+        if self.contains_key(code) {
+            self.values.to_mut()[code.ordinal()].take()
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Removes all entries from the map.
+    ///
+    pub fn clear(&mut self) {
+        self.values = Cow::Borrowed(&[]);
+    }
+
+    ///
+    /// Returns an iterator over the entries in the map, in ordinal order of
+    /// the codes.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (T, &V)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .filter_map(|(ordinal, value)| Some((T::from_ordinal(ordinal)?, value.as_ref()?)))
+    }
+
+    ///
+    /// Returns an iterator over the codes in the map, in ordinal order.
+    ///
+    pub fn keys(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().map(|(code, _)| code)
+    }
+
+    ///
+    /// Returns an iterator over the values in the map, in ordinal order of
+    /// the codes.
+    ///
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.values.iter().filter_map(Option::as_ref)
+    }
+
+    ///
+    /// Returns the set of codes in the map.
+    ///
+    pub fn key_set(&self) -> CodeSet<T> {
+        self.keys().collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const fn word_and_bit(ordinal: usize) -> (usize, u64) {
+    (
+        ordinal / u64::BITS as usize,
+        1 << (ordinal % u64::BITS as usize),
+    )
+}
+
+fn trim_words(words: &[u64]) -> &[u64] {
+    let len = words
+        .iter()
+        .rposition(|word| *word != 0)
+        .map_or(0, |i| i + 1);
+    &words[..len]
+}
+
+fn trim_values<V>(values: &[Option<V>]) -> &[Option<V>] {
+    let len = values
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |i| i + 1);
+    &values[..len]
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{CodeMap, CodeSet};
+    use crate::CodeEnum;
+    use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt::{Display, Formatter};
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    impl<T> Serialize for CodeSet<T>
+    where
+        T: CodeEnum + Display,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter().map(|code| code.to_string()))
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for CodeSet<T>
+    where
+        T: CodeEnum + FromStr,
+        T::Err: Display,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(CodeSetVisitor(PhantomData))
+        }
+    }
+
+    struct CodeSetVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for CodeSetVisitor<T>
+    where
+        T: CodeEnum + FromStr,
+        T::Err: Display,
+    {
+        type Value = CodeSet<T>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "a sequence of code strings")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut set = CodeSet::new();
+            while let Some(code) = seq.next_element::<String>()? {
+                set.insert(T::from_str(&code).map_err(A::Error::custom)?);
+            }
+            Ok(set)
+        }
+    }
+
+    impl<T, V> Serialize for CodeMap<T, V>
+    where
+        T: CodeEnum + Display,
+        V: Clone + Serialize + 'static,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(self.iter().map(|(code, value)| (code.to_string(), value)))
+        }
+    }
+
+    impl<'de, T, V> Deserialize<'de> for CodeMap<T, V>
+    where
+        T: CodeEnum + FromStr,
+        T::Err: Display,
+        V: Clone + Deserialize<'de> + 'static,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(CodeMapVisitor(PhantomData))
+        }
+    }
+
+    struct CodeMapVisitor<T, V>(PhantomData<(T, V)>);
+
+    impl<'de, T, V> Visitor<'de> for CodeMapVisitor<T, V>
+    where
+        T: CodeEnum + FromStr,
+        T::Err: Display,
+        V: Clone + Deserialize<'de> + 'static,
+    {
+        type Value = CodeMap<T, V>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "a map with code string keys")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut map = CodeMap::new();
+            while let Some((code, value)) = access.next_entry::<String, V>()? {
+                map.insert(T::from_str(&code).map_err(A::Error::custom)?, value);
+            }
+            Ok(map)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // Enough codes for ordinals to span three words of the bit set.
+    macro_rules! test_codes {
+        ($($code:ident),*) => {
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            enum Code {
+                $($code),*
+            }
+
+            const ALL_CODES: &[Code] = &[$(Code::$code),*];
+        };
+    }
+
+    test_codes!(
+        C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11, C12, C13, C14, C15, C16, C17, C18, C19,
+        C20, C21, C22, C23, C24, C25, C26, C27, C28, C29, C30, C31, C32, C33, C34, C35, C36, C37,
+        C38, C39, C40, C41, C42, C43, C44, C45, C46, C47, C48, C49, C50, C51, C52, C53, C54, C55,
+        C56, C57, C58, C59, C60, C61, C62, C63, C64, C65, C66, C67, C68, C69, C70, C71, C72, C73,
+        C74, C75, C76, C77, C78, C79, C80, C81, C82, C83, C84, C85, C86, C87, C88, C89, C90, C91,
+        C92, C93, C94, C95, C96, C97, C98, C99, C100, C101, C102, C103, C104, C105, C106, C107,
+        C108, C109, C110, C111, C112, C113, C114, C115, C116, C117, C118, C119, C120, C121, C122,
+        C123, C124, C125, C126, C127, C128, C129
+    );
+
+    code_enum!(Code, ALL_CODES);
+
+    impl std::fmt::Display for Code {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+
+    impl FromStr for Code {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            ALL_CODES
+                .iter()
+                .find(|code| code.to_string() == s)
+                .copied()
+                .ok_or_else(|| format!("unknown code {:?}", s))
+        }
+    }
+
+    static EMPTY: CodeSet<Code> = code_set![];
+    static LOW: CodeSet<Code> = code_set![Code::C0, Code::C1, Code::C2];
+    static HIGH: CodeSet<Code> = code_set![Code::C64, Code::C100];
+    static NAMES: CodeMap<Code, &str> = code_map! {
+        Code::C1 => "one",
+        Code::C65 => "sixty-five",
+    };
+
+    #[test]
+    fn test_ordinals() {
+        assert_eq!(Code::count(), 130);
+        assert_eq!(Code::C63.ordinal(), 63);
+        assert_eq!(Code::C64.ordinal(), 64);
+        assert_eq!(Code::C129.ordinal(), 129);
+        assert_eq!(Code::from_ordinal(128), Some(Code::C128));
+        assert_eq!(Code::from_ordinal(130), None);
+    }
+
+    #[test]
+    fn test_static_set() {
+        assert!(EMPTY.is_empty());
+        assert_eq!(LOW.len(), 3);
+        assert!(LOW.contains(&Code::C2));
+        assert!(!LOW.contains(&Code::C63));
+        assert!(!LOW.contains(&Code::C100));
+        assert_eq!(HIGH.iter().collect::<Vec<_>>(), vec![Code::C64, Code::C100]);
+    }
+
+    #[test]
+    fn test_set_insert_remove() {
+        let mut set = LOW.clone();
+        assert!(set.insert(Code::C100));
+        assert!(!set.insert(Code::C100));
+        assert!(set.remove(&Code::C0));
+        assert!(!set.remove(&Code::C0));
+        assert!(!set.remove(&Code::C65));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Code::C1, Code::C2, Code::C100]
+        );
+        set.clear();
+        assert_eq!(set, EMPTY);
+    }
+
+    #[test]
+    fn test_set_iteration_across_words() {
+        let mut set = CodeSet::new();
+        for code in [
+            Code::C129,
+            Code::C64,
+            Code::C0,
+            Code::C128,
+            Code::C63,
+            Code::C127,
+            Code::C65,
+        ] {
+            set.insert(code);
+        }
+        assert_eq!(set.len(), 7);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![
+                Code::C0,
+                Code::C63,
+                Code::C64,
+                Code::C65,
+                Code::C127,
+                Code::C128,
+                Code::C129
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let all = CodeSet::<Code>::all();
+        assert_eq!(all.len(), 130);
+        assert_eq!(all.iter().last(), Some(Code::C129));
+
+        let a: CodeSet<Code> = code_set![Code::C1, Code::C63, Code::C64, Code::C100];
+        let b: CodeSet<Code> = code_set![Code::C63, Code::C65, Code::C100, Code::C128];
+        assert_eq!(
+            &a | &b,
+            code_set![
+                Code::C1,
+                Code::C63,
+                Code::C64,
+                Code::C65,
+                Code::C100,
+                Code::C128
+            ]
+        );
+        assert_eq!(&a & &b, code_set![Code::C63, Code::C100]);
+        assert_eq!(&a - &b, code_set![Code::C1, Code::C64]);
+        assert_eq!(
+            &a ^ &b,
+            code_set![Code::C1, Code::C64, Code::C65, Code::C128]
+        );
+        assert_eq!(&a - &b, a.difference(&b));
+
+        let complement = (&LOW | &HIGH).complement();
+        assert_eq!(complement.len(), 125);
+        assert!(complement.contains(&Code::C63));
+        assert!(!complement.contains(&Code::C64));
+        assert!(complement.contains(&Code::C65));
+        assert!(complement.contains(&Code::C129));
+        assert_eq!(&complement | &(&LOW | &HIGH), all);
+
+        assert!(LOW.is_subset(&all));
+        assert!(all.is_superset(&HIGH));
+        assert!(LOW.is_disjoint(&HIGH));
+        assert!(!all.is_disjoint(&HIGH));
+        assert!(!a.is_disjoint(&b));
+        assert!((&a & &b).is_subset(&b));
+    }
+
+    #[test]
+    fn test_set_equality_ignores_capacity() {
+        let mut set = LOW.clone();
+        set.insert(Code::C100);
+        set.remove(&Code::C100);
+        assert_eq!(set, LOW);
+        assert_eq!(format!("{:?}", LOW), "{C0, C1, C2}");
+    }
+
+    #[test]
+    fn test_static_map() {
+        assert_eq!(NAMES.len(), 2);
+        assert_eq!(NAMES.get(&Code::C1), Some(&"one"));
+        assert_eq!(NAMES.get(&Code::C0), None);
+        assert_eq!(NAMES.get(&Code::C100), None);
+        assert_eq!(NAMES.keys().collect::<Vec<_>>(), vec![Code::C1, Code::C65]);
+        assert_eq!(NAMES.key_set(), code_set![Code::C1, Code::C65]);
+    }
+
+    #[test]
+    fn test_map_insert_remove() {
+        let mut map = NAMES.clone();
+        assert_eq!(map.insert(Code::C100, "one hundred"), None);
+        assert_eq!(map.insert(Code::C1, "uno"), Some("one"));
+        assert_eq!(map.remove(&Code::C65), Some("sixty-five"));
+        assert_eq!(map.remove(&Code::C65), None);
+        if let Some(value) = map.get_mut(&Code::C1) {
+            *value = "eins";
+        }
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(Code::C1, &"eins"), (Code::C100, &"one hundred")]
+        );
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map, CodeMap::new());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&HIGH).unwrap();
+        assert_eq!(json, r#"["C64","C100"]"#);
+        assert_eq!(serde_json::from_str::<CodeSet<Code>>(&json).unwrap(), HIGH);
+        assert!(serde_json::from_str::<CodeSet<Code>>(r#"["C130"]"#).is_err());
+
+        let json = serde_json::to_string(&NAMES).unwrap();
+        assert_eq!(json, r#"{"C1":"one","C65":"sixty-five"}"#);
+        let map: CodeMap<Code, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(map.get(&Code::C65).map(String::as_str), Some("sixty-five"));
+        assert_eq!(map.key_set(), NAMES.key_set());
+    }
+}
@@ -5,6 +5,8 @@ Support capabilities for `codes-*` packages.
 
 * `build` - Tools for build scripts.
 * `csv_tools` - Tools for reading and processing CSV files, requires `build`.
+* `serde` - Enables serialization of the `CodeSet` and `CodeMap` collections,
+  and the `serde_helpers` module.
 
 */
 
@@ -37,7 +39,8 @@ Support capabilities for `codes-*` packages.
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -77,6 +80,43 @@ pub trait VariableLengthCode {
     fn max_length() -> usize;
 }
 
+///
+/// Implemented by code types with a fixed, enumerable, set of values, usually
+/// generated from the data files published by the standard's agency.
+///
+/// Each value has an ordinal, its index in the slice returned by
+/// [CodeEnum::all]. Ordinals are stable for a given version of a package but
+/// may change when the package's data is updated, they should not be
+/// persisted in place of the code itself.
+///
+pub trait CodeEnum: Sized + Copy + 'static {
+    ///
+    /// Return all values of this type, in ordinal order.
+    ///
+    fn all() -> &'static [Self];
+
+    ///
+    /// Return the number of values of this type.
+    ///
+    fn count() -> usize {
+        Self::all().len()
+    }
+
+    ///
+    /// Return the ordinal of this value, such that
+    /// `Self::all()[v.ordinal()] == v`.
+    ///
+    fn ordinal(&self) -> usize;
+
+    ///
+    /// Return the value with the ordinal `ordinal`, or `None` if `ordinal`
+    /// is not less than [CodeEnum::count].
+    ///
+    fn from_ordinal(ordinal: usize) -> Option<Self> {
+        Self::all().get(ordinal).copied()
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Macros
 // ------------------------------------------------------------------------------------------------
@@ -209,6 +249,56 @@ macro_rules! variable_length_code {
     };
 }
 
+///
+/// Implement `Serialize` and `Deserialize` for `$type_name` using its
+/// `Display` and `FromStr` implementations, so that values are represented by
+/// their code string rather than, for example, an enumeration's variant name.
+/// This requires the calling package to depend on `serde`.
+///
+#[macro_export]
+macro_rules! code_serde {
+    ($type_name:ty) => {
+        impl ::serde::Serialize for $type_name {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $type_name {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+                <Self as ::std::str::FromStr>::from_str(&s).map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+///
+/// Implement [CodeEnum] for a field-less enumeration `$type_name` where
+/// `$all_codes` is an array of all variants in declaration order, and so the
+/// ordinal is simply the variant's discriminant.
+///
+#[macro_export]
+macro_rules! code_enum {
+    ($type_name:ty, $all_codes:expr) => {
+        impl $crate::CodeEnum for $type_name {
+            fn all() -> &'static [Self] {
+                &$all_codes
+            }
+
+            fn ordinal(&self) -> usize {
+                *self as usize
+            }
+        }
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -224,6 +314,72 @@ macro_rules! variable_length_code {
 pub mod error;
 pub use error::CodeParseError;
 
+#[macro_use]
+pub mod collections;
+
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+
 #[cfg(feature = "build")]
 #[macro_use]
 pub mod build;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::CodeEnum;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Fruit {
+        Apple,
+        Banana,
+        Cherry,
+    }
+
+    const ALL_FRUIT: [Fruit; 3] = [Fruit::Apple, Fruit::Banana, Fruit::Cherry];
+
+    code_enum!(Fruit, ALL_FRUIT);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Port(u16);
+
+    const ALL_PORTS: [Port; 3] = [Port(22), Port(80), Port(443)];
+
+    impl CodeEnum for Port {
+        fn all() -> &'static [Self] {
+            &ALL_PORTS
+        }
+
+        fn ordinal(&self) -> usize {
+            match self.0 {
+                22 => 0,
+                80 => 1,
+                443 => 2,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_enum_ordinals() {
+        assert_eq!(Fruit::count(), 3);
+        assert_eq!(Fruit::all(), &ALL_FRUIT);
+        assert_eq!(Fruit::Cherry.ordinal(), 2);
+        assert_eq!(Fruit::from_ordinal(1), Some(Fruit::Banana));
+        assert_eq!(Fruit::from_ordinal(3), None);
+        for (i, fruit) in Fruit::all().iter().enumerate() {
+            assert_eq!(fruit.ordinal(), i);
+        }
+    }
+
+    #[test]
+    fn test_newtype_ordinals() {
+        assert_eq!(Port::count(), 3);
+        assert_eq!(Port(443).ordinal(), 2);
+        assert_eq!(Port::from_ordinal(0), Some(Port(22)));
+        assert_eq!(Port::from_ordinal(usize::MAX), None);
+    }
+}
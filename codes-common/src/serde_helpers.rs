@@ -0,0 +1,82 @@
+/*!
+Provides functions used by `codes-*` packages to implement the modules used
+with the `#[serde(with = "...")]` attribute to represent a code in one of its
+alternate forms.
+
+*/
+
+use serde::de::{Error, Unexpected, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Deserialize a numeric code, such as the ISO 3166 or ISO 4217 numeric
+/// codes, from either an integer or a string of digits; the latter allows for
+/// the zero-padded form `"004"` commonly used in text.
+///
+pub fn deserialize_numeric_code<'de, D>(deserializer: D) -> Result<u16, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(NumericCodeVisitor)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct NumericCodeVisitor;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Visitor<'_> for NumericCodeVisitor {
+    type Value = u16;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a numeric code as an integer or string of digits")
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        u16::try_from(v).map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        u16::try_from(v).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        if !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()) {
+            v.parse()
+                .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+        } else {
+            Err(E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_numeric_code() {
+        let mut de = serde_json::Deserializer::from_str("840");
+        assert_eq!(deserialize_numeric_code(&mut de).unwrap(), 840);
+        let mut de = serde_json::Deserializer::from_str(r#""004""#);
+        assert_eq!(deserialize_numeric_code(&mut de).unwrap(), 4);
+        let mut de = serde_json::Deserializer::from_str(r#""-4""#);
+        assert!(deserialize_numeric_code(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str("70000");
+        assert!(deserialize_numeric_code(&mut de).is_err());
+    }
+}
@@ -67,7 +67,8 @@ By default only the `serde` feature is enabled.
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -99,7 +100,10 @@ pub const GS1_GLN: Standard = Standard::new(
     "GLN",
     "Global Location Number (GLN)",
     "https://www.gs1.org/standards/id-keys/gln",
-);
+)
+.with_edition("Release 24.0")
+.with_publication_date("2024-01")
+.with_registration_authority(Agency::GS1);
 
 ///
 /// Encapsulates the complete Global Location Number (GLN) including
@@ -101,7 +101,8 @@ assert_eq!(some_charset, iso_8859_1);
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -119,3 +120,30 @@ assert_eq!(some_charset, iso_8859_1);
 // ------------------------------------------------------------------------------------------------
 
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(CharacterSetCode::try_from(4).unwrap(), CHARSET_4);
+        assert!(CharacterSetCode::try_from(9999).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&CHARSET_4).unwrap();
+        assert_eq!(json, "4");
+        assert_eq!(
+            serde_json::from_str::<CharacterSetCode>(&json).unwrap(),
+            CHARSET_4
+        );
+        assert!(serde_json::from_str::<CharacterSetCode>("9999").is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use codes_common::build::{
+    default_finalize_for, input_file_name, make_default_renderer, process, Data, DataRow,
+    SimpleData,
+};
+use std::fs::read_to_string;
+use tera::Value;
+
+const TYPE_NAME: &str = "LanguageTag";
+
+const RECORD_SEPARATOR: &str = "%%";
+
+// The record types that are looked up by a single subtag, `grandfathered` and
+// `redundant` records are looked up by their complete tag.
+const SUBTAG_TYPES: [&str; 5] = ["language", "extlang", "script", "region", "variant"];
+const TAG_TYPES: [&str; 2] = ["grandfathered", "redundant"];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    process(
+        || Ok(SimpleData::new(TYPE_NAME)),
+        process_registry_data,
+        default_finalize_for,
+        make_default_renderer("registry._rs", "registry.rs"),
+    )
+}
+
+fn process_registry_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::error::Error>> {
+    let file_name = input_file_name("language-subtag-registry");
+
+    for record in read_records(&read_to_string(file_name)?) {
+        let record_type = match field(&record, "Type") {
+            Some(record_type) => record_type,
+            // The first record only contains the File-Date.
+            None => continue,
+        };
+
+        let tag = if SUBTAG_TYPES.contains(&record_type) {
+            field(&record, "Subtag").unwrap()
+        } else if TAG_TYPES.contains(&record_type) {
+            field(&record, "Tag").unwrap()
+        } else {
+            return Err(format!("Unknown record type {:?}", record_type).into());
+        };
+        // Private use ranges such as `qaa..qtz` are handled in code.
+        if tag.contains("..") {
+            continue;
+        }
+
+        let mut row: DataRow = Default::default();
+        row.insert("type".to_string(), record_type.into());
+        row.insert("tag".to_string(), tag.into());
+        row.insert("key".to_string(), tag.to_ascii_lowercase().into());
+        row.insert(
+            "deprecated".to_string(),
+            field(&record, "Deprecated").is_some().into(),
+        );
+        if let Some(preferred_value) = field(&record, "Preferred-Value") {
+            row.insert("preferred_value".to_string(), preferred_value.into());
+        }
+        row.insert(
+            "prefixes".to_string(),
+            Value::Array(
+                record
+                    .iter()
+                    .filter(|(name, _)| name == "Prefix")
+                    .map(|(_, value)| value.clone().into())
+                    .collect(),
+            ),
+        );
+
+        data.insert_row(
+            &format!("{}:{}", record_type, tag.to_ascii_lowercase()),
+            row,
+        );
+    }
+
+    Ok(data)
+}
+
+// The registry uses the record-jar format, fields are `Name: value` and long
+// values are folded onto continuation lines that start with whitespace.
+fn read_records(s: &str) -> Vec<Vec<(String, String)>> {
+    s.split(&format!("\n{}\n", RECORD_SEPARATOR))
+        .map(|record| {
+            let mut fields: Vec<(String, String)> = Default::default();
+            for line in record.lines() {
+                if line.starts_with(char::is_whitespace) {
+                    if let Some((_, value)) = fields.last_mut() {
+                        value.push(' ');
+                        value.push_str(line.trim());
+                    }
+                } else if let Some((name, value)) = line.split_once(':') {
+                    fields.push((name.trim().to_string(), value.trim().to_string()));
+                }
+            }
+            fields
+        })
+        .collect()
+}
+
+fn field<'a>(record: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    record
+        .iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, value)| value.as_str())
+}
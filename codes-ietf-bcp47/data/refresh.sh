@@ -0,0 +1,7 @@
+#!/usr/bin/env sh
+
+# Registration Authority:
+# [IANA](https://www.iana.org/assignments/language-subtag-registry)
+# Format described in RFC 5646, section 3.1
+
+curl -o language-subtag-registry "https://www.iana.org/assignments/language-subtag-registry/language-subtag-registry"
@@ -0,0 +1,776 @@
+/*!
+This package contains an implementation of the
+[IETF BCP 47](https://www.rfc-editor.org/info/bcp47) Tags for Identifying
+Languages specification.
+
+BCP 47 is the IETF best current practice for language tags, it currently
+comprises [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646), which defines
+the syntax of tags, and [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647),
+which defines matching. A tag such as `zh-Hant-HK` is composed of a number of
+subtags, each of which is drawn from an existing standard.
+
+| Subtag    | Example   | Source                                          |
+| --------- | --------- | ----------------------------------------------- |
+| language  | `zh`      | ISO 639-1, ISO 639-3, or ISO 639-5              |
+| extlang   | `yue`     | IANA Language Subtag Registry                   |
+| script    | `Hant`    | ISO 15924                                       |
+| region    | `HK`      | ISO 3166-1 alpha-2, or UN M49 for larger areas  |
+| variant   | `1901`    | IANA Language Subtag Registry                   |
+| extension | `u-ca-gregory` | Registered extensions `t` and `u`          |
+| privateuse| `x-private`| Unregistered                                   |
+
+Tags are validated on parsing using the code types in the `codes-iso-639`,
+`codes-iso-15924`, `codes-iso-3166`, and `codes-un-m49` packages, and the
+IANA [Language Subtag
+Registry](https://www.iana.org/assignments/language-subtag-registry) for
+those subtags not defined in any of these standards.
+
+# Example
+
+```rust
+use codes_ietf_bcp47::{LanguageTag, RegionSubtag};
+use codes_iso_3166::part_1::CountryCode;
+use std::str::FromStr;
+
+let tag = LanguageTag::from_str("ZH-hant-hk").unwrap();
+
+assert_eq!(tag.to_string(), "zh-Hant-HK");
+assert_eq!(tag.region(), Some(&RegionSubtag::Country(CountryCode::HK)));
+
+let tag = LanguageTag::from_str("iw-BU").unwrap();
+
+assert!(tag.is_deprecated());
+assert_eq!(tag.canonicalize().to_string(), "he-MM");
+```
+
+# Features
+
+By default only the `serde` feature is enabled.
+
+* `serde` - Enables serialization of the [LanguageTag] type, as a string.
+
+*/
+
+#![warn(
+    unknown_lints,
+    // ---------- Stylistic
+    absolute_paths_not_starting_with_crate,
+    elided_lifetimes_in_paths,
+    explicit_outlives_requirements,
+    macro_use_extern_crate,
+    nonstandard_style, /* group */
+    noop_method_call,
+    rust_2018_idioms,
+    single_use_lifetimes,
+    trivial_casts,
+    trivial_numeric_casts,
+    // ---------- Future
+    future_incompatible, /* group */
+    rust_2021_compatibility, /* group */
+    // ---------- Public
+    missing_debug_implementations,
+    // missing_docs,
+    unreachable_pub,
+    // ---------- Unsafe
+    unsafe_code,
+    unsafe_op_in_unsafe_fn,
+    // ---------- Unused
+    unused, /* group */
+)]
+#![deny(
+    // ---------- Public
+    exported_private_dependencies,
+    private_interfaces,
+    private_bounds,
+    // ---------- Deprecated
+    anonymous_parameters,
+    bare_trait_objects,
+    ellipsis_inclusive_range_patterns,
+    // ---------- Unsafe
+    deref_nullptr,
+    drop_bounds,
+    dyn_drop,
+)]
+
+use codes_agency::{standardized_type, Agency, Standard};
+use codes_common::error::{invalid_character, invalid_format, invalid_length, unknown_value};
+use codes_common::{code_as_str, code_impl};
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An instance of the `Standard` struct defined in the
+/// [`codes_agency`](https://docs.rs/codes-agency/latest/codes_agency/)
+/// package that describes the BCP 47 specification.
+///
+pub const BCP_47: Standard = Standard::new_with_long_ref(
+    Agency::IETF,
+    "BCP 47",
+    "RFC 5646",
+    "Tags for Identifying Languages",
+    "https://www.rfc-editor.org/info/bcp47",
+)
+.with_edition("RFC 5646")
+.with_publication_date("2009-09")
+.with_registration_authority(Agency::IANA);
+
+///
+/// A valid language tag, as described in RFC 5646. Parsing a tag checks
+/// that it is both well-formed and that each subtag is a known value, the
+/// string form of the tag uses the recommended case for each subtag, for
+/// example `en-Latn-US` rather than `EN-LATN-US`.
+///
+/// Note that a valid tag may still contain deprecated subtags, see
+/// [LanguageTag::is_deprecated] and [LanguageTag::canonicalize].
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct LanguageTag {
+    tag: String,
+    language: Option<LanguageSubtag>,
+    extended_language: Option<String>,
+    script: Option<ScriptSubtag>,
+    region: Option<RegionSubtag>,
+    variants: Vec<String>,
+    extensions: Vec<Extension>,
+    private_use: Vec<String>,
+    grandfathered: bool,
+}
+
+///
+/// An extension to a language tag, such as `u-ca-gregory`, identified by a
+/// single character, the singleton, and followed by one or more subtags.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Extension {
+    singleton: char,
+    subtags: Vec<String>,
+}
+
+pub use codes_common::CodeParseError as LanguageTagError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const TYPE_NAME: &str = "LanguageTag";
+
+const SEPARATOR: char = '-';
+
+const PRIVATE_USE_SINGLETON: &str = "x";
+
+// The only singletons registered with IANA, `t` (RFC 6497) and `u` (RFC 6067).
+const REGISTERED_EXTENSIONS: [char; 2] = ['t', 'u'];
+
+impl FromStr for LanguageTag {
+    type Err = LanguageTagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(invalid_length(TYPE_NAME, 0));
+        }
+        if let Some(c) = s
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == SEPARATOR))
+        {
+            return Err(invalid_character(TYPE_NAME, c));
+        }
+
+        let lower_case = s.to_ascii_lowercase();
+        if let Some(record) = registry::grandfathered(&lower_case) {
+            return Ok(Self {
+                tag: record.tag.to_string(),
+                grandfathered: true,
+                ..Self::empty()
+            });
+        }
+
+        let subtags: Vec<&str> = lower_case.split(SEPARATOR).collect();
+        if subtags
+            .iter()
+            .any(|subtag| subtag.is_empty() || subtag.len() > 8)
+        {
+            return Err(invalid_format(TYPE_NAME, s));
+        }
+
+        let mut tag = Self::empty();
+        let mut subtags = subtags.into_iter().peekable();
+
+        if subtags.peek() != Some(&PRIVATE_USE_SINGLETON) {
+            let language = subtags.next().unwrap();
+            if !is_alpha(language, 2..=8) {
+                return Err(invalid_format(TYPE_NAME, s));
+            }
+            tag.language = Some(LanguageSubtag::parse(language)?);
+
+            if let Some(extended_language) = subtags.next_if(|s| is_alpha(s, 3..=3)) {
+                let record = registry::extlang(extended_language)
+                    .filter(|record| record.prefixes.contains(&language))
+                    .ok_or_else(|| unknown_value(TYPE_NAME, extended_language))?;
+                tag.extended_language = Some(record.tag.to_string());
+            }
+
+            if let Some(script) = subtags.next_if(|s| is_alpha(s, 4..=4)) {
+                tag.script = Some(ScriptSubtag::parse(script)?);
+            }
+
+            if let Some(region) =
+                subtags.next_if(|s| is_alpha(s, 2..=2) || (s.len() == 3 && is_digits(s)))
+            {
+                tag.region = Some(RegionSubtag::parse(region)?);
+            }
+
+            while let Some(variant) = subtags.next_if(|s| is_variant(s)) {
+                let record =
+                    registry::variant(variant).ok_or_else(|| unknown_value(TYPE_NAME, variant))?;
+                if tag.variants.iter().any(|existing| existing == record.tag)
+                    || !tag.has_prefix(record.prefixes)
+                {
+                    return Err(invalid_format(TYPE_NAME, s));
+                }
+                tag.variants.push(record.tag.to_string());
+            }
+
+            while let Some(singleton) =
+                subtags.next_if(|s| s.len() == 1 && *s != PRIVATE_USE_SINGLETON)
+            {
+                let singleton = singleton.chars().next().unwrap();
+                if !REGISTERED_EXTENSIONS.contains(&singleton) {
+                    return Err(unknown_value(TYPE_NAME, singleton.to_string()));
+                }
+                if tag.extensions.iter().any(|ext| ext.singleton == singleton) {
+                    return Err(invalid_format(TYPE_NAME, s));
+                }
+                let mut extension = Extension {
+                    singleton,
+                    subtags: Default::default(),
+                };
+                while let Some(subtag) = subtags.next_if(|s| s.len() > 1) {
+                    extension.subtags.push(subtag.to_string());
+                }
+                if extension.subtags.is_empty() {
+                    return Err(invalid_format(TYPE_NAME, s));
+                }
+                tag.extensions.push(extension);
+            }
+        }
+
+        if subtags.next_if_eq(&PRIVATE_USE_SINGLETON).is_some() {
+            tag.private_use = subtags.by_ref().map(str::to_string).collect();
+            if tag.private_use.is_empty() {
+                return Err(invalid_format(TYPE_NAME, s));
+            }
+        }
+
+        if subtags.next().is_some() {
+            return Err(invalid_format(TYPE_NAME, s));
+        }
+
+        tag.tag = tag.format_tag();
+        Ok(tag)
+    }
+}
+
+impl TryFrom<String> for LanguageTag {
+    type Error = LanguageTagError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+code_impl!(LanguageTag, as_str, str, String, to_string);
+
+code_as_str!(LanguageTag, tag);
+
+standardized_type!(LanguageTag, BCP_47);
+
+impl LanguageTag {
+    ///
+    /// Return the primary language subtag, this is `None` for tags that
+    /// consist only of private use subtags, such as `x-whatever`, and for
+    /// grandfathered tags.
+    ///
+    pub fn language(&self) -> Option<&LanguageSubtag> {
+        self.language.as_ref()
+    }
+
+    ///
+    /// Return the extended language subtag, such as `yue` in `zh-yue`, if
+    /// present.
+    ///
+    pub fn extended_language(&self) -> Option<&str> {
+        self.extended_language.as_deref()
+    }
+
+    ///
+    /// Return the script subtag, if present.
+    ///
+    pub fn script(&self) -> Option<&ScriptSubtag> {
+        self.script.as_ref()
+    }
+
+    ///
+    /// Return the region subtag, if present.
+    ///
+    pub fn region(&self) -> Option<&RegionSubtag> {
+        self.region.as_ref()
+    }
+
+    ///
+    /// Return the variant subtags, in the order they appear in the tag.
+    ///
+    pub fn variants(&self) -> &[String] {
+        &self.variants
+    }
+
+    ///
+    /// Return any extensions, in the order they appear in the tag.
+    ///
+    pub fn extensions(&self) -> &[Extension] {
+        &self.extensions
+    }
+
+    ///
+    /// Return the private use subtags, those following the `x` singleton.
+    ///
+    pub fn private_use(&self) -> &[String] {
+        &self.private_use
+    }
+
+    ///
+    /// Returns `true` if this is one of the tags registered under RFC 3066
+    /// that does not conform to the current syntax, such as `i-klingon`, or
+    /// conforms but has subtags that are not otherwise registered, such as
+    /// `zh-min-nan`.
+    ///
+    pub fn is_grandfathered(&self) -> bool {
+        self.grandfathered
+    }
+
+    ///
+    /// Returns `true` if this tag consists only of private use subtags.
+    ///
+    pub fn is_private_use(&self) -> bool {
+        !self.grandfathered && self.language.is_none()
+    }
+
+    ///
+    /// Returns `true` if the tag as a whole, or any of its subtags, has been
+    /// deprecated in the registry.
+    ///
+    pub fn is_deprecated(&self) -> bool {
+        let lower_case = self.tag.to_ascii_lowercase();
+        let tag_record = if self.grandfathered {
+            registry::grandfathered(&lower_case)
+        } else {
+            registry::redundant(&lower_case)
+        };
+        tag_record
+            .iter()
+            .chain(
+                self.language
+                    .as_ref()
+                    .and_then(LanguageSubtag::record)
+                    .iter(),
+            )
+            .chain(self.script.as_ref().and_then(ScriptSubtag::record).iter())
+            .chain(self.region.as_ref().and_then(RegionSubtag::record).iter())
+            .chain(
+                self.variants
+                    .iter()
+                    .filter_map(|variant| registry::variant(variant))
+                    .collect::<Vec<_>>()
+                    .iter(),
+            )
+            .any(|record| record.deprecated)
+    }
+
+    ///
+    /// Return the canonical form of this tag as described in RFC 5646,
+    /// section 4.5:
+    ///
+    /// 1. Extensions are ordered by their singleton.
+    /// 2. Grandfathered and redundant tags are replaced by their preferred
+    ///    value, if there is one; `i-klingon` becomes `tlh`.
+    /// 3. Subtags are replaced by their preferred value, if there is one;
+    ///    `iw` becomes `he`.
+    /// 4. Extended language subtags are replaced by their primary language
+    ///    form; `zh-yue` becomes `yue`.
+    ///
+    pub fn canonicalize(&self) -> Self {
+        if self.grandfathered {
+            return registry::grandfathered(&self.tag.to_ascii_lowercase())
+                .and_then(|record| record.preferred_value)
+                .and_then(|preferred_value| Self::from_str(preferred_value).ok())
+                .unwrap_or_else(|| self.clone());
+        }
+
+        let mut tag = self.clone();
+        tag.extensions.sort_by_key(|extension| extension.singleton);
+
+        // Redundant tags may be followed by further subtags, so match the
+        // longest prefix that is registered.
+        let base = tag.base_subtags();
+        for length in (1..=base.len()).rev() {
+            let key = base[..length].join("-").to_ascii_lowercase();
+            if let Some(preferred_value) =
+                registry::redundant(&key).and_then(|record| record.preferred_value)
+            {
+                let mut replaced: Vec<String> = preferred_value
+                    .split(SEPARATOR)
+                    .map(str::to_string)
+                    .collect();
+                replaced.extend(base[length..].iter().cloned());
+                if let Ok(reparsed) = Self::from_str(&replaced.join("-")) {
+                    tag = Self {
+                        extensions: tag.extensions,
+                        private_use: tag.private_use,
+                        ..reparsed
+                    };
+                }
+                break;
+            }
+        }
+
+        if let Some(record) = tag.language.as_ref().and_then(LanguageSubtag::record) {
+            if let Some(Ok(language)) = record.preferred_value.map(LanguageSubtag::parse) {
+                tag.language = Some(language);
+            }
+        }
+        if let Some(extended_language) = tag.extended_language.take() {
+            let preferred_value = registry::extlang(&extended_language)
+                .and_then(|record| record.preferred_value)
+                .unwrap_or(extended_language.as_str());
+            if let Ok(language) = LanguageSubtag::parse(preferred_value) {
+                tag.language = Some(language);
+            }
+        }
+        if let Some(record) = tag.script.as_ref().and_then(ScriptSubtag::record) {
+            if let Some(Ok(script)) = record
+                .preferred_value
+                .map(|s| ScriptSubtag::parse(&s.to_ascii_lowercase()))
+            {
+                tag.script = Some(script);
+            }
+        }
+        if let Some(record) = tag.region.as_ref().and_then(RegionSubtag::record) {
+            if let Some(Ok(region)) = record
+                .preferred_value
+                .map(|s| RegionSubtag::parse(&s.to_ascii_lowercase()))
+            {
+                tag.region = Some(region);
+            }
+        }
+        let mut variants: Vec<String> = Default::default();
+        for variant in &tag.variants {
+            let variant = registry::variant(variant)
+                .and_then(|record| record.preferred_value)
+                .unwrap_or(variant.as_str());
+            if !variants.iter().any(|existing| existing == variant) {
+                variants.push(variant.to_string());
+            }
+        }
+        tag.variants = variants;
+
+        tag.tag = tag.format_tag();
+        tag
+    }
+
+    fn empty() -> Self {
+        Self {
+            tag: Default::default(),
+            language: None,
+            extended_language: None,
+            script: None,
+            region: None,
+            variants: Default::default(),
+            extensions: Default::default(),
+            private_use: Default::default(),
+            grandfathered: false,
+        }
+    }
+
+    // The language, extended language, script, region, and variant subtags,
+    // in tag order and formatted.
+    fn base_subtags(&self) -> Vec<String> {
+        self.language
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.extended_language.iter().cloned())
+            .chain(self.script.iter().map(ToString::to_string))
+            .chain(self.region.iter().map(ToString::to_string))
+            .chain(self.variants.iter().cloned())
+            .collect()
+    }
+
+    // A variant with registered prefixes may only follow the subtags of one
+    // of them, `1994` requires `sl-rozaj` but may follow `sl-IT-rozaj-biske`.
+    fn has_prefix(&self, prefixes: &[&str]) -> bool {
+        if prefixes.is_empty() {
+            return true;
+        }
+        let subtags = self.base_subtags();
+        prefixes.iter().any(|prefix| {
+            let mut prefix = prefix.split(SEPARATOR);
+            prefix.next().map(str::to_ascii_lowercase)
+                == subtags.first().map(|s| s.to_ascii_lowercase())
+                && prefix.all(|p| subtags[1..].iter().any(|s| s.eq_ignore_ascii_case(p)))
+        })
+    }
+
+    fn format_tag(&self) -> String {
+        let mut subtags = self.base_subtags();
+        subtags.extend(self.extensions.iter().map(ToString::to_string));
+        if !self.private_use.is_empty() {
+            subtags.push(PRIVATE_USE_SINGLETON.to_string());
+            subtags.extend(self.private_use.iter().cloned());
+        }
+        subtags.join("-")
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Extension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.singleton, self.subtags.join("-"))
+    }
+}
+
+impl Extension {
+    ///
+    /// Return the single character that identifies this extension.
+    ///
+    pub fn singleton(&self) -> char {
+        self.singleton
+    }
+
+    ///
+    /// Return the subtags that follow the singleton.
+    ///
+    pub fn subtags(&self) -> &[String] {
+        &self.subtags
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_alpha(s: &str, lengths: std::ops::RangeInclusive<usize>) -> bool {
+    lengths.contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_digits(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_digit())
+}
+
+// Variants are 5 to 8 characters, or 4 characters starting with a digit.
+fn is_variant(s: &str) -> bool {
+    (5..=8).contains(&s.len()) || (s.len() == 4 && s.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod registry;
+
+#[doc(hidden)]
+mod subtags;
+pub use subtags::{LanguageSubtag, RegionSubtag, ScriptSubtag};
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codes_iso_15924::ScriptCode;
+    use codes_iso_3166::part_1::CountryCode;
+    use codes_iso_639::part_1;
+    use codes_un_m49::UN_M69_REGION_419;
+
+    fn canonical(s: &str) -> String {
+        LanguageTag::from_str(s).unwrap().canonicalize().to_string()
+    }
+
+    #[test]
+    fn test_parse_components() {
+        let tag = LanguageTag::from_str("zh-Hant-HK").unwrap();
+        assert_eq!(
+            tag.language(),
+            Some(&LanguageSubtag::Alpha2(part_1::LanguageCode::Zh))
+        );
+        assert_eq!(tag.script(), Some(&ScriptSubtag::Script(ScriptCode::Hant)));
+        assert_eq!(tag.region(), Some(&RegionSubtag::Country(CountryCode::HK)));
+
+        let tag = LanguageTag::from_str("es-419").unwrap();
+        assert_eq!(tag.region(), Some(&RegionSubtag::Area(UN_M69_REGION_419)));
+        assert_eq!(tag.as_ref(), "es-419");
+
+        let tag = LanguageTag::from_str("sl-rozaj-biske-u-ca-gregory-x-private").unwrap();
+        assert_eq!(tag.variants(), &["rozaj", "biske"]);
+        assert_eq!(tag.extensions()[0].singleton(), 'u');
+        assert_eq!(tag.extensions()[0].subtags(), &["ca", "gregory"]);
+        assert_eq!(tag.private_use(), &["private"]);
+    }
+
+    #[test]
+    fn test_case_normalization() {
+        assert_eq!(
+            LanguageTag::from_str("EN-latn-us-X-ABC")
+                .unwrap()
+                .to_string(),
+            "en-Latn-US-x-abc"
+        );
+        assert_eq!(
+            LanguageTag::from_str("I-KLINGON").unwrap().to_string(),
+            "i-klingon"
+        );
+    }
+
+    #[test]
+    fn test_special_tags() {
+        let tag = LanguageTag::from_str("x-whatever").unwrap();
+        assert!(tag.is_private_use());
+        assert_eq!(tag.language(), None);
+
+        let tag = LanguageTag::from_str("zh-min-nan").unwrap();
+        assert!(tag.is_grandfathered());
+        assert!(!tag.is_private_use());
+
+        assert!(LanguageTag::from_str("qaa-Qaaa-QM").is_ok());
+        assert!(LanguageTag::from_str("zh-yue-HK").is_ok());
+        assert!(LanguageTag::from_str("de-CH-1901").is_ok());
+    }
+
+    #[test]
+    fn test_registered_subtags() {
+        let tag = LanguageTag::from_str("pt-ao1990").unwrap();
+        assert_eq!(tag.variants(), &["ao1990"]);
+        assert!(LanguageTag::from_str("gl-ao1990").is_ok());
+
+        let tag = LanguageTag::from_str("sa-vaidika").unwrap();
+        assert_eq!(tag.variants(), &["vaidika"]);
+
+        let tag = LanguageTag::from_str("ar-afb").unwrap();
+        assert_eq!(tag.extended_language(), Some("afb"));
+        assert_eq!(tag.canonicalize().to_string(), "afb");
+
+        let tag = LanguageTag::from_str("en-basiceng").unwrap();
+        assert_eq!(tag.variants(), &["basiceng"]);
+
+        let tag = LanguageTag::from_str("hy-arevela").unwrap();
+        assert_eq!(tag.variants(), &["arevela"]);
+        assert!(tag.is_deprecated());
+    }
+
+    #[test]
+    fn test_registry_only_subtags() {
+        let tag = LanguageTag::from_str("sr-Latn-ekavsk").unwrap();
+        assert_eq!(tag.variants(), &["ekavsk"]);
+        assert!(LanguageTag::from_str("sr-ijekavsk").is_ok());
+        assert!(LanguageTag::from_str("hr-ekavsk").is_err());
+
+        let tag = LanguageTag::from_str("ar-aao").unwrap();
+        assert_eq!(tag.extended_language(), Some("aao"));
+        assert_eq!(tag.canonicalize().to_string(), "aao");
+
+        let tag = LanguageTag::from_str("sh").unwrap();
+        assert_eq!(tag.language(), Some(&LanguageSubtag::Registered("sh")));
+        assert!(!tag.is_deprecated());
+    }
+
+    #[test]
+    fn test_retired_languages() {
+        let tag = LanguageTag::from_str("aam").unwrap();
+        assert!(tag.is_deprecated());
+        assert_eq!(tag.canonicalize().to_string(), "aas");
+        assert_eq!(canonical("kzj-MY"), "dtp-MY");
+        // retired in favour of dzo, which has the part 1 code dz.
+        assert_eq!(canonical("adp"), "dz");
+    }
+
+    #[test]
+    fn test_variant_prefixes() {
+        assert!(LanguageTag::from_str("pt-BR-ao1990").is_ok());
+        assert!(LanguageTag::from_str("sl-IT-rozaj-biske-1994").is_ok());
+        assert!(LanguageTag::from_str("ja-Latn-hepburn-heploc").is_ok());
+        assert!(LanguageTag::from_str("sl-fonipa").is_ok());
+        for s in [
+            "en-ao1990",
+            "de-vaidika",
+            "fr-basiceng",
+            "en-arevela",
+            "sl-1994",
+            "it-SL-rozaj",
+            "zh-wadegile",
+        ] {
+            assert!(
+                LanguageTag::from_str(s).is_err(),
+                "expecting {:?} to fail",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_tags() {
+        for s in [
+            "",
+            "en_US",
+            "en--US",
+            "en-verylongsubtag",
+            "eng",
+            "xx",
+            "en-Xxxx",
+            "en-UK",
+            "es-724",
+            "en-US-US",
+            "de-1901-1901",
+            "en-yue",
+            "en-b-ext",
+            "en-u-ca-u-nu",
+            "en-u",
+            "en-x",
+            "i-unknown",
+        ] {
+            assert!(
+                LanguageTag::from_str(s).is_err(),
+                "expecting {:?} to fail",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(canonical("i-klingon"), "tlh");
+        assert_eq!(canonical("en-GB-oed"), "en-GB-oxendict");
+        assert_eq!(canonical("zh-min"), "zh-min");
+        assert_eq!(canonical("iw-BU"), "he-MM");
+        assert_eq!(canonical("zh-yue-HK"), "yue-HK");
+        assert_eq!(canonical("sgn-US-x-foo"), "ase-x-foo");
+        assert_eq!(canonical("en-u-nu-latn-t-de"), "en-t-de-u-nu-latn");
+        assert_eq!(canonical("zh-Hant-HK"), "zh-Hant-HK");
+    }
+
+    #[test]
+    fn test_is_deprecated() {
+        assert!(LanguageTag::from_str("iw").unwrap().is_deprecated());
+        assert!(LanguageTag::from_str("en-BU").unwrap().is_deprecated());
+        assert!(LanguageTag::from_str("i-klingon").unwrap().is_deprecated());
+        assert!(!LanguageTag::from_str("zh-min").unwrap().is_deprecated());
+        assert!(!LanguageTag::from_str("he-MM").unwrap().is_deprecated());
+    }
+}
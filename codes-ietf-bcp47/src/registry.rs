@@ -0,0 +1,29 @@
+/*!
+Provides lookups into the IANA Language Subtag Registry for those subtags and
+tags that cannot be validated by the code types in other packages, such as
+variants, extended languages, and deprecated values.
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A record from the registry, the `tag` is in the case used by the
+/// registry, which is also the canonical case.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Record {
+    pub(crate) tag: &'static str,
+    pub(crate) deprecated: bool,
+    pub(crate) preferred_value: Option<&'static str>,
+    pub(crate) prefixes: &'static [&'static str],
+}
+
+// ------------------------------------------------------------------------------------------------
+//
+// The rest of this file is generated by the package build script.
+//
+// ------------------------------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
@@ -0,0 +1,208 @@
+/*!
+Provides the types for the validated subtags of a [crate::LanguageTag].
+ */
+
+use crate::registry;
+use codes_common::error::unknown_value;
+use codes_iso_15924::ScriptCode;
+use codes_iso_3166::part_1::{CountryCode, Status};
+use codes_iso_639::{part_1, part_3, part_5};
+use codes_un_m49::{RegionClassificationCode, RegionKind};
+use std::{fmt::Display, str::FromStr};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The primary language subtag of a language tag. Where a language has both
+/// a two-letter and a three-letter code only the two-letter code is valid.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LanguageSubtag {
+    /// A two-letter ISO 639-1 language code.
+    Alpha2(part_1::LanguageCode),
+    /// A three-letter ISO 639-3 language code.
+    Alpha3(part_3::LanguageCode),
+    /// A three-letter ISO 639-5 language family or group code.
+    Family(part_5::LanguageCode),
+    /// A subtag only present in the IANA registry, commonly a deprecated code
+    /// such as `iw`.
+    Registered(&'static str),
+    /// A private use subtag in the range `qaa..qtz`.
+    PrivateUse(String),
+}
+
+///
+/// The script subtag of a language tag.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptSubtag {
+    /// An ISO 15924 script code.
+    Script(ScriptCode),
+    /// A subtag only present in the IANA registry, such as the deprecated
+    /// `Qaai`.
+    Registered(&'static str),
+    /// A private use subtag in the range `Qaaa..Qabx`.
+    PrivateUse(String),
+}
+
+///
+/// The region subtag of a language tag.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegionSubtag {
+    /// An officially assigned ISO 3166-1 alpha-2 country code.
+    Country(CountryCode),
+    /// A UN M49 code for an area larger than a single country, such as `419`
+    /// for Latin America and the Caribbean.
+    Area(RegionClassificationCode),
+    /// A subtag only present in the IANA registry, such as `EU` or the
+    /// deprecated `BU`.
+    Registered(&'static str),
+    /// A private use subtag, one of `AA`, `QM..QZ`, `XA..XZ`, or `ZZ`.
+    PrivateUse(String),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const TYPE_NAME: &str = "LanguageTag";
+
+impl Display for LanguageSubtag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alpha2(code) => write!(f, "{}", code),
+            Self::Alpha3(code) => write!(f, "{}", code),
+            Self::Family(code) => write!(f, "{}", code),
+            Self::Registered(code) => write!(f, "{}", code),
+            Self::PrivateUse(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+impl LanguageSubtag {
+    ///
+    /// Parse a lower case primary language subtag.
+    ///
+    pub(crate) fn parse(s: &str) -> Result<Self, crate::LanguageTagError> {
+        let is_private_use = |s: &str| s.len() == 3 && ("qaa"..="qtz").contains(&s);
+        if s.len() == 2 {
+            if let Ok(code) = part_1::LanguageCode::from_str(s) {
+                return Ok(Self::Alpha2(code));
+            }
+        } else if is_private_use(s) {
+            return Ok(Self::PrivateUse(s.to_string()));
+        } else if s.len() == 3 {
+            if let Ok(code) = part_3::LanguageCode::from_str(s) {
+                // The shortest code must be used, and so `eng` is not valid.
+                if code.part_1_code().is_none() {
+                    return Ok(Self::Alpha3(code));
+                }
+                return Err(unknown_value(TYPE_NAME, s));
+            }
+            if let Ok(code) = part_5::LanguageCode::from_str(s) {
+                return Ok(Self::Family(code));
+            }
+        }
+        registry::language(s)
+            .map(|record| Self::Registered(record.tag))
+            .ok_or_else(|| unknown_value(TYPE_NAME, s))
+    }
+
+    pub(crate) fn record(&self) -> Option<&'static registry::Record> {
+        match self {
+            Self::Registered(code) => registry::language(code),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ScriptSubtag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Script(code) => write!(f, "{}", code),
+            Self::Registered(code) => write!(f, "{}", code),
+            Self::PrivateUse(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+impl ScriptSubtag {
+    ///
+    /// Parse a lower case script subtag.
+    ///
+    pub(crate) fn parse(s: &str) -> Result<Self, crate::LanguageTagError> {
+        let title_case = format!("{}{}", s[..1].to_ascii_uppercase(), &s[1..]);
+        if ("qaaa"..="qabx").contains(&s) {
+            Ok(Self::PrivateUse(title_case))
+        } else if let Ok(code) = ScriptCode::from_str(&title_case) {
+            Ok(Self::Script(code))
+        } else {
+            registry::script(s)
+                .map(|record| Self::Registered(record.tag))
+                .ok_or_else(|| unknown_value(TYPE_NAME, s))
+        }
+    }
+
+    pub(crate) fn record(&self) -> Option<&'static registry::Record> {
+        match self {
+            Self::Registered(code) => registry::script(&code.to_ascii_lowercase()),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for RegionSubtag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Country(code) => write!(f, "{}", code),
+            Self::Area(code) => write!(f, "{:03}", code.code()),
+            Self::Registered(code) => write!(f, "{}", code),
+            Self::PrivateUse(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+impl RegionSubtag {
+    ///
+    /// Parse a lower case region subtag.
+    ///
+    pub(crate) fn parse(s: &str) -> Result<Self, crate::LanguageTagError> {
+        let upper_case = s.to_ascii_uppercase();
+        if s.len() == 2 {
+            if upper_case == "AA"
+                || upper_case == "ZZ"
+                || ("QM"..="QZ").contains(&upper_case.as_str())
+                || ("XA"..="XZ").contains(&upper_case.as_str())
+            {
+                return Ok(Self::PrivateUse(upper_case));
+            }
+            if let Ok(code) = CountryCode::from_str(&upper_case) {
+                if code.status() == Status::OfficiallyAssigned {
+                    return Ok(Self::Country(code));
+                }
+            }
+        } else if let Ok(code) = RegionClassificationCode::from_str(s) {
+            // Countries are identified by their alpha-2 code, not numeric.
+            if code.kind() != RegionKind::Country {
+                return Ok(Self::Area(code));
+            }
+        }
+        registry::region(s)
+            .map(|record| Self::Registered(record.tag))
+            .ok_or_else(|| unknown_value(TYPE_NAME, s))
+    }
+
+    pub(crate) fn record(&self) -> Option<&'static registry::Record> {
+        match self {
+            Self::Registered(code) => registry::region(&code.to_ascii_lowercase()),
+            _ => None,
+        }
+    }
+}
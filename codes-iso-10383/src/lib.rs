@@ -83,6 +83,7 @@ By default only the `serde` feature is enabled, the [MarketIdCode::code] and
 * `dates` - Adds the [MarketIdCode::creation_date], [MarketIdCode::last_update_date], [MarketIdCode::last_validation_date], and [MarketIdCode::expiration_date] methods.
 * `real_dates` - Used the `DateTime<Utc>` types from the `chrono` crate for date functions **Work In Progress**
 * `comments` - Adds the [MarketIdCode::comments] method.
+* `category_description` - Adds the [Category::description] method.
 
 */
 
@@ -115,7 +116,8 @@ By default only the `serde` feature is enabled, the [MarketIdCode::code] and
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
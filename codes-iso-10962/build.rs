@@ -1,47 +1,207 @@
-use codes_common::build::{default_finalize_for, make_default_renderer, process, Data, SimpleData};
+use codes_common::build::{
+    default_finalize_for, input_file_name, make_default_renderer, process, Data, SimpleData,
+};
+use csv::StringRecord;
+use std::{
+    fs::{read_dir, File},
+    str::FromStr,
+};
+use tera::{Map, Value};
 
-#[allow(dead_code)]
-const TYPE_NAME: &str = "FinancialInstrumentClassification";
+const CATEGORY_TYPE_NAME: &str = "Category";
+const GROUP_TYPE_NAME: &str = "Group";
 
-#[allow(dead_code)]
-#[derive(Debug)]
-struct Details {
-    code: char,
-    name: String,
-    description: String,
-    label: String,
-}
+const SHEET_DIR: &str = "csv-sheets";
+const CATEGORY_SHEET_SUFFIX: &str = "_Category.csv";
 
-#[allow(dead_code)]
-#[derive(Debug)]
-struct Category {
-    details: Details,
-    groups: Vec<Group>,
-}
+const ATTRIBUTE_COUNT: usize = 4;
 
-#[allow(dead_code)]
-#[derive(Debug)]
-struct Group {
-    details: Details,
-    version: u16,
-}
-
-#[allow(dead_code)]
-#[derive(Debug)]
-struct Attribute {
-    details: Details,
-    version: u16,
-}
+type CategorySheet = (String, Vec<StringRecord>);
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     process(
-        || Ok(SimpleData::new(TYPE_NAME)),
-        process_input_data,
+        || Ok(SimpleData::new(CATEGORY_TYPE_NAME)),
+        process_category_data,
         default_finalize_for,
-        make_default_renderer("lib._rs", "generated.rs"),
+        make_default_renderer("category._rs", "category.rs"),
+    )?;
+
+    process(
+        || Ok(SimpleData::new(GROUP_TYPE_NAME)),
+        process_group_data,
+        default_finalize_for,
+        make_default_renderer("group._rs", "group.rs"),
     )
 }
 
-fn process_input_data(data: SimpleData) -> Result<SimpleData, Box<dyn std::error::Error>> {
+fn process_category_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::error::Error>> {
+    for record in read_sheet("Categories.csv")?.into_iter().skip(1) {
+        let id = record.get(0).unwrap();
+        if !id.is_empty() {
+            let mut row: Map<String, Value> = Default::default();
+            row.insert("code".to_string(), id.into());
+            row.insert(
+                "name".to_string(),
+                clean_text(record.get(1).unwrap()).into(),
+            );
+            row.insert(
+                "description".to_string(),
+                clean_text(record.get(2).unwrap()).into(),
+            );
+            row.insert("groups".to_string(), Value::Array(Default::default()));
+            data.insert_row(id, row);
+        }
+    }
+
+    for (category, groups) in category_sheets()? {
+        let row = data.get_mut(&category).unwrap();
+        let all_groups = row.get_mut("groups").unwrap().as_array_mut().unwrap();
+        for group in groups {
+            all_groups.push(format!("{}{}", category, group.get(0).unwrap()).into());
+        }
+        all_groups.sort_by(|lhs, rhs| lhs.as_str().cmp(&rhs.as_str()));
+    }
+
+    Ok(data)
+}
+
+fn process_group_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::error::Error>> {
+    for (category, groups) in category_sheets()? {
+        for group in groups {
+            let id = format!("{}{}", category, group.get(0).unwrap());
+
+            let mut row: Map<String, Value> = Default::default();
+            row.insert("code".to_string(), id.clone().into());
+            row.insert("category".to_string(), category.clone().into());
+            row.insert("name".to_string(), clean_text(group.get(1).unwrap()).into());
+            row.insert(
+                "description".to_string(),
+                clean_text(group.get(2).unwrap()).into(),
+            );
+
+            let version = u16::from_str(group.get(5).unwrap())?;
+            row.insert("version".to_string(), version.into());
+
+            let label = group.get(4).unwrap();
+            row.insert(
+                "attributes".to_string(),
+                Value::Array(process_attribute_data(label)?),
+            );
+
+            data.insert_row(&id, row);
+        }
+    }
+
     Ok(data)
 }
+
+fn process_attribute_data(label: &str) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
+    let mut attributes: Vec<Map<String, Value>> = Default::default();
+
+    for record in read_sheet(&format!("{}.csv", label))?.into_iter().skip(1) {
+        let heading = record.get(0).unwrap();
+        let code = record.get(1).unwrap_or_default();
+        if heading.ends_with("attribute") {
+            let mut attribute: Map<String, Value> = Default::default();
+            attribute.insert(
+                "description".to_string(),
+                clean_text(record.get(2).unwrap()).into(),
+            );
+            attribute.insert("values".to_string(), Value::Array(Default::default()));
+            attributes.push(attribute);
+        } else if heading.is_empty() && code.len() == 1 {
+            let position = attributes.len() - 1;
+            let values = attributes[position]
+                .get_mut("values")
+                .unwrap()
+                .as_array_mut()
+                .unwrap();
+            let existing: Vec<&str> = values
+                .iter()
+                .map(|v| v.get("code").unwrap().as_str().unwrap())
+                .collect();
+            // The source data includes a small number of duplicated codes, where the
+            // code pattern column has the correct value we use it, else the duplicate
+            // is simply dropped.
+            let code = if existing.contains(&code) {
+                match code_from_pattern(record.get(3).unwrap_or_default(), position) {
+                    Some(code) if !existing.contains(&code.as_str()) => code,
+                    _ => continue,
+                }
+            } else {
+                code.to_string()
+            };
+            let mut value: Map<String, Value> = Default::default();
+            value.insert("code".to_string(), code.into());
+            value.insert(
+                "description".to_string(),
+                clean_text(record.get(2).unwrap()).into(),
+            );
+            values.push(Value::Object(value));
+        }
+    }
+
+    assert_eq!(
+        attributes.len(),
+        ATTRIBUTE_COUNT,
+        "expecting {} attributes for group {}",
+        ATTRIBUTE_COUNT,
+        label
+    );
+
+    Ok(attributes.into_iter().map(Value::Object).collect())
+}
+
+fn category_sheets() -> Result<Vec<CategorySheet>, Box<dyn std::error::Error>> {
+    let mut sheets: Vec<CategorySheet> = Default::default();
+
+    for entry in read_dir(format!("data/{}", SHEET_DIR))? {
+        let file_name = entry?.file_name().to_string_lossy().to_string();
+        if file_name.ends_with(CATEGORY_SHEET_SUFFIX) {
+            let records = read_sheet(&file_name)?;
+            let category = records[0]
+                .get(1)
+                .unwrap()
+                .trim_matches(|c| c == '(' || c == ')')
+                .to_string();
+            let groups = records
+                .into_iter()
+                .skip(2)
+                .take_while(|record| !record.get(0).unwrap().is_empty())
+                .collect();
+            sheets.push((category, groups));
+        }
+    }
+
+    Ok(sheets)
+}
+
+fn read_sheet(name: &str) -> Result<Vec<StringRecord>, Box<dyn std::error::Error>> {
+    let file_name = input_file_name(&format!("{}/{}", SHEET_DIR, name));
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(File::open(file_name)?);
+
+    Ok(rdr
+        .records()
+        .collect::<Result<Vec<StringRecord>, csv::Error>>()?)
+}
+
+fn code_from_pattern(pattern: &str, position: usize) -> Option<String> {
+    pattern
+        .split('-')
+        .nth(position + 2)
+        .filter(|code| code.len() == 1 && code.chars().all(|c| c.is_ascii_uppercase()))
+        .map(|code| code.to_string())
+}
+
+fn clean_text(s: &str) -> String {
+    s.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+}
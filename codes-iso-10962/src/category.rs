@@ -0,0 +1,11 @@
+/*!
+Provides the [Category] type, the first character of a CFI code.
+*/
+
+// ------------------------------------------------------------------------------------------------
+//
+// The rest of this file is generated by the package build script.
+//
+// ------------------------------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/category.rs"));
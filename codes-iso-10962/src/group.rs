@@ -0,0 +1,108 @@
+/*!
+Provides the [Group] type, the first two characters of a CFI code, as well as
+the [Attribute] descriptions for the remaining four characters.
+
+Each group defines four attributes and for each of these the set of
+[AttributeValue]s that are valid in that position. In all positions the value
+`X`, *not applicable/undefined*, is also valid even if not listed explicitly.
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Describes one of the four attribute positions defined by a [Group].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Attribute {
+    description: &'static str,
+    values: &'static [AttributeValue],
+}
+
+///
+/// Describes a single value that is valid for an [Attribute].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AttributeValue {
+    code: char,
+    description: &'static str,
+}
+
+///
+/// The value used in any attribute position to denote that the attribute is
+/// not applicable, or undefined.
+///
+pub const NOT_APPLICABLE: AttributeValue =
+    AttributeValue::new(NOT_APPLICABLE_CODE, "Not applicable/undefined");
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const NOT_APPLICABLE_CODE: char = 'X';
+
+impl Attribute {
+    pub(crate) const fn new(description: &'static str, values: &'static [AttributeValue]) -> Self {
+        Self {
+            description,
+            values,
+        }
+    }
+
+    /// Returns a description of this attribute.
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the values defined for this attribute, this does not include
+    /// [NOT_APPLICABLE] unless it is included explicitly by the standard.
+    pub const fn values(&self) -> &'static [AttributeValue] {
+        self.values
+    }
+
+    /// Returns the value for the provided code, if it is valid for this
+    /// attribute. The code `X` is always valid and will return
+    /// [NOT_APPLICABLE] if not explicitly defined.
+    pub fn value(&self, code: char) -> Option<&'static AttributeValue> {
+        self.values
+            .iter()
+            .find(|value| value.code == code)
+            .or(if code == NOT_APPLICABLE_CODE {
+                Some(&NOT_APPLICABLE)
+            } else {
+                None
+            })
+    }
+
+    /// Returns `true` if the provided code is valid for this attribute.
+    pub fn is_valid(&self, code: char) -> bool {
+        self.value(code).is_some()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl AttributeValue {
+    pub(crate) const fn new(code: char, description: &'static str) -> Self {
+        Self { code, description }
+    }
+
+    /// Returns the single character code for this value.
+    pub const fn code(&self) -> char {
+        self.code
+    }
+
+    /// Returns a description of this value.
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+//
+// The rest of this file is generated by the package build script.
+//
+// ------------------------------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/group.rs"));
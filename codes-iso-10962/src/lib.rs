@@ -1,17 +1,51 @@
 /*!
 This package contains an implementation of the [ISO
-// 10962](https://www.iso.org/standard/81140.html) Securities and related
-// financial instruments — Classification of financial instruments (CFI) code
-// specification.
+10962](https://www.iso.org/standard/81140.html) Securities and related
+financial instruments — Classification of financial instruments (CFI) code
+specification.
 
-More detailed description, with
+The CFI code is a six-character alphabetic code used to classify financial
+instruments. The first character identifies the [category::Category], the
+second the [group::Group] within the category, and the remaining four
+characters identify attributes specific to the group. In each attribute
+position the letter `X` denotes an attribute that is not applicable or
+undefined.
+
+| Position | Usage       | Example                             |
+| -------- | ----------- | ----------------------------------- |
+| 1        | Category    | `E` - Equities                      |
+| 2        | Group       | `S` - Common/ordinary shares        |
+| 3        | Attribute 1 | `V` - Voting                        |
+| 4        | Attribute 2 | `U` - Free (unrestricted)           |
+| 5        | Attribute 3 | `F` - Fully paid                    |
+| 6        | Attribute 4 | `R` - Registered                    |
 
 # Example
 
-YYYYY
+```rust
+use codes_iso_10962::{category::Category, group::Group, Cfi};
+use std::str::FromStr;
+
+let cfi = Cfi::from_str("ESVUFR").unwrap();
+
+assert_eq!(cfi.category(), Category::E);
+assert_eq!(cfi.group(), Group::ES);
+assert_eq!(cfi.group().name(), "Common/ordinary shares");
+assert_eq!(
+    cfi.attribute_values()[0].description(),
+    "Voting (each share has one vote)"
+);
+
+assert!(Cfi::from_str("ESZUFR").is_err());
+```
 
 # Features
 
+By default only the `serde` feature is enabled.
+
+* `serde` - Enables serialization of the [Cfi], [category::Category], and
+  [group::Group] types.
+
 */
 
 #![warn(
@@ -43,7 +77,8 @@ YYYYY
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -54,10 +89,227 @@ YYYYY
     dyn_drop,
 )]
 
+use crate::group::{AttributeValue, Group};
+use codes_agency::{standardized_type, Agency, Standard};
+use codes_common::error::{invalid_character, invalid_format, invalid_length};
+use codes_common::{code_as_str, code_impl, fixed_length_code, FixedLengthCode};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An instance of the `Standard` struct defined in the
+/// [`codes_agency`](https://docs.rs/codes-agency/latest/codes_agency/)
+/// package that describes the ISO-10962 specification.
+///
+pub const ISO_10962: Standard = Standard::new_with_long_ref(
+    Agency::ISO,
+    "10962",
+    "ISO 10962:2021",
+    "Securities and related financial instruments — Classification of financial instruments (CFI) code",
+    "https://www.iso.org/standard/81140.html",
+)
+.with_edition("2021")
+.with_publication_date("2021-10")
+.with_registration_authority(Agency::ANNA);
+
+///
+/// A Classification of Financial Instruments (CFI) code. On parsing each
+/// character is validated against the [category::Category], [group::Group],
+/// and the [group::Attribute]s defined for the group.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Cfi(String);
+
+pub use codes_common::CodeParseError as CfiError;
+
+///
+/// The name of the [Cfi] type in earlier versions of this package.
+///
+#[deprecated(note = "Please use `Cfi` instead")]
+pub type FinancialInstrumentClassification = Cfi;
+
+///
+/// The name of the [CfiError] type in earlier versions of this package.
+///
+#[deprecated(note = "Please use `CfiError` instead")]
+pub type FinancialInstrumentClassificationError = CfiError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FromStr for Cfi {
+    type Err = CfiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != Self::fixed_length() {
+            return Err(invalid_length("Cfi", s.len()));
+        }
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_uppercase()) {
+            return Err(invalid_character("Cfi", c));
+        }
+        let group = Group::from_str(&s[..2])?;
+        if group
+            .attributes()
+            .iter()
+            .zip(s[2..].chars())
+            .all(|(attribute, c)| attribute.is_valid(c))
+        {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(invalid_format("Cfi", s))
+        }
+    }
+}
+
+impl TryFrom<String> for Cfi {
+    type Error = CfiError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+code_impl!(Cfi, as_str, str, String, to_string);
+
+code_as_str!(Cfi);
+
+fixed_length_code!(Cfi, 6);
+
+standardized_type!(Cfi, ISO_10962);
+
+impl Cfi {
+    ///
+    /// Return the category, the first character, of this code.
+    ///
+    pub fn category(&self) -> category::Category {
+        self.group().category()
+    }
+
+    ///
+    /// Return the group, the first two characters, of this code.
+    ///
+    pub fn group(&self) -> Group {
+        Group::from_str(&self.0[..2]).unwrap()
+    }
+
+    ///
+    /// Return the four attribute characters of this code.
+    ///
+    pub fn attributes(&self) -> &str {
+        &self.0[2..]
+    }
+
+    ///
+    /// Return the value for each of the four attribute characters of this
+    /// code, as defined by the code's group.
+    ///
+    pub fn attribute_values(&self) -> [&'static AttributeValue; 4] {
+        let attributes = self.group().attributes();
+        let mut chars = self.attributes().chars();
+        [0, 1, 2, 3].map(|i| attributes[i].value(chars.next().unwrap()).unwrap())
+    }
+
+    ///
+    /// Return a human-readable description of each of the six positions of
+    /// this code; the category name, group name, and the description of each
+    /// attribute value.
+    ///
+    pub fn descriptions(&self) -> [&'static str; 6] {
+        let group = self.group();
+        let values = self.attribute_values();
+        [
+            group.category().name(),
+            group.name(),
+            values[0].description(),
+            values[1].description(),
+            values[2].description(),
+            values[3].description(),
+        ]
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
-//
-// The rest of this file is generated by the package build script.
-//
+// Modules
 // ------------------------------------------------------------------------------------------------
 
-include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+pub mod category;
+
+pub mod group;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::category::Category;
+    use crate::group::Group;
+    use crate::Cfi;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_valid_cfi() {
+        let cfi = Cfi::from_str("ESVUFR").unwrap();
+        assert_eq!(cfi.category(), Category::E);
+        assert_eq!(cfi.group(), Group::ES);
+        assert_eq!(cfi.attributes(), "VUFR");
+        assert_eq!(
+            cfi.descriptions(),
+            [
+                "Equities",
+                "Common/ordinary shares",
+                "Voting (each share has one vote)",
+                "Free (unrestricted)",
+                "Fully paid",
+                "Registered (securities are recorded in the name of the owner on the books of the issuer or the issuer's registrar and can only be transferred to another owner when endorsed by the registered owner)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_not_applicable_attributes() {
+        let cfi = Cfi::from_str("DBXXXX").unwrap();
+        assert_eq!(
+            cfi.attribute_values()[0].description(),
+            "Not applicable/undefined"
+        );
+        assert!(Cfi::from_str("MMRXXX").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_cfi() {
+        assert!(Cfi::from_str("ESVUF").is_err());
+        assert!(Cfi::from_str("esvufr").is_err());
+        assert!(Cfi::from_str("EZVUFR").is_err());
+        assert!(Cfi::from_str("ESZUFR").is_err());
+        assert!(Cfi::from_str("MMRYXX").is_err());
+    }
+
+    #[test]
+    fn test_category_groups() {
+        assert!(Category::E.groups().contains(&Group::ES));
+        assert!(Category::E
+            .groups()
+            .iter()
+            .all(|group| group.category() == Category::E));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let cfi = Cfi::from_str("ESVUFR").unwrap();
+        let json = serde_json::to_string(&cfi).unwrap();
+        assert_eq!(json, r#""ESVUFR""#);
+        assert_eq!(serde_json::from_str::<Cfi>(&json).unwrap(), cfi);
+        assert!(serde_json::from_str::<Cfi>(r#""EZVUFR""#).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use codes_common::build::csv::process_tsv_input;
+use codes_common::build::{
+    default_finalize_for, make_default_renderer, process, Data, DataRow, SimpleData,
+};
+use codes_common::insert_field;
+use csv::StringRecord;
+use std::str::FromStr;
+use tera::{Map, Value};
+
+const TYPE_NAME: &str = "Iban";
+
+// Prefixes in the registry that are not ISO 3166-1 country codes.
+const REGISTERED_PREFIXES: &[&str] = &["XK"];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    process(
+        || Ok(SimpleData::new(TYPE_NAME)),
+        |data| process_tsv_input(data, "iban-registry.txt", process_input_row),
+        |data| {
+            let country_ids: Vec<Value> = data
+                .rows()
+                .iter()
+                .filter(|(_, row)| !row.contains_key("registered"))
+                .map(|(id, _)| id.clone().into())
+                .collect();
+            let mut ctx = default_finalize_for(data)?;
+            ctx.insert("country_ids", &country_ids);
+            Ok(ctx)
+        },
+        make_default_renderer("registry._rs", "registry.rs"),
+    )
+}
+
+fn process_input_row(
+    record: StringRecord,
+    row: &mut DataRow,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let id = record.get(0).unwrap().to_string();
+
+    // ID field
+    insert_field!(id.clone() => row, "country_code");
+    if REGISTERED_PREFIXES.contains(&id.as_str()) {
+        insert_field!(Value::Bool(true) => row, "registered");
+    }
+
+    // Required fields
+    insert_field!(record, row, 1 => "country_name", 6 => "iban_example");
+    insert_field!(record, 2 => row, "iban_length", usize);
+
+    let iban_length = usize::from_str(record.get(2).unwrap())?;
+    let (structure, bban_length) = parse_bban_structure(record.get(3).unwrap())?;
+    if bban_length + 4 != iban_length {
+        return Err(format!(
+            "BBAN structure for {} has length {}, expecting {}",
+            id,
+            bban_length,
+            iban_length - 4
+        )
+        .into());
+    }
+    insert_field!(Value::Array(structure) => row, "bban_structure");
+
+    // Optional fields
+    if let Some(position) = parse_position(record.get(4).unwrap(), bban_length)? {
+        insert_field!(position => row, "bank_identifier");
+    }
+    if let Some(position) = parse_position(record.get(5).unwrap(), bban_length)? {
+        insert_field!(position => row, "branch_identifier");
+    }
+
+    Ok(id)
+}
+
+// The registry describes a BBAN as a sequence of segments such as `4!a6!n8!n`,
+// where the `!` denotes a fixed length and the final character is one of
+// `n` (digits), `a` (upper case letters), or `c` (alphanumeric).
+fn parse_bban_structure(s: &str) -> Result<(Vec<Value>, usize), Box<dyn std::error::Error>> {
+    let mut segments = Vec::new();
+    let mut total = 0;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let (length, tail) = rest
+            .split_once('!')
+            .ok_or_else(|| format!("BBAN structure {:?} is missing '!'", s))?;
+        let length = usize::from_str(length)?;
+        let class = match tail.chars().next() {
+            Some('n') => "Numeric",
+            Some('a') => "Alphabetic",
+            Some('c') => "Alphanumeric",
+            other => {
+                return Err(format!("BBAN structure {:?} has invalid class {:?}", s, other).into())
+            }
+        };
+        let mut segment = Map::new();
+        segment.insert("length".to_string(), length.into());
+        segment.insert("class".to_string(), class.into());
+        segments.push(Value::Object(segment));
+        total += length;
+        rest = &tail[1..];
+    }
+    Ok((segments, total))
+}
+
+// Positions are 1-based and inclusive, `"5-8"`, and are converted into the
+// 0-based exclusive range `4..8`.
+fn parse_position(
+    s: &str,
+    bban_length: usize,
+) -> Result<Option<Value>, Box<dyn std::error::Error>> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| format!("Identifier position {:?} is not a range", s))?;
+        let (start, end) = (usize::from_str(start)?, usize::from_str(end)?);
+        if start == 0 || start > end || end > bban_length {
+            return Err(format!("Identifier position {:?} is not within the BBAN", s).into());
+        }
+        let mut position = Map::new();
+        position.insert("start".to_string(), (start - 1).into());
+        position.insert("end".to_string(), end.into());
+        Ok(Some(Value::Object(position)))
+    }
+}
@@ -0,0 +1,34 @@
+#!/usr/bin/env sh
+
+# Registration Authority:
+# [SWIFT](https://www.swift.com/standards/data-standards/iban-international-bank-account-number)
+#
+# The registry text file has one row per data element and one column per
+# country, the following transposes the elements used by the build script
+# into one row per country. Kosovo (XK) is kept although its prefix is not an
+# ISO 3166-1 code, see `REGISTERED_PREFIXES` in the build script.
+#
+# The registry must be downloaded manually, in text format, from the page
+# above and its path passed as the only argument to this script.
+
+SOURCE=${1:?"usage: refresh.sh <IBAN registry text file>"}
+
+iconv -f ISO-8859-1 -t UTF-8 "${SOURCE}" \
+    | tr -d '\r' \
+    | awk -F '\t' '
+        $1 == "Name of country" { for (i = 2; i <= NF; i++) name[i] = $i; n = NF }
+        $1 == "IBAN prefix country code (ISO 3166)" { for (i = 2; i <= NF; i++) code[i] = $i }
+        $1 == "IBAN length" { for (i = 2; i <= NF; i++) length_[i] = $i }
+        $1 == "BBAN structure" { for (i = 2; i <= NF; i++) bban[i] = $i }
+        $1 == "Bank identifier position within the BBAN" { for (i = 2; i <= NF; i++) bank[i] = $i }
+        $1 == "Branch identifier position within the BBAN" { for (i = 2; i <= NF; i++) branch[i] = $i }
+        $1 == "IBAN electronic format example" { for (i = 2; i <= NF; i++) example[i] = $i }
+        END {
+            print "country_code\tcountry_name\tiban_length\tbban_structure\tbank_identifier\tbranch_identifier\tiban_example"
+            for (i = 2; i <= n; i++) {
+                if (bank[i] == "N/A") bank[i] = ""
+                if (branch[i] == "N/A") branch[i] = ""
+                gsub(/ /, "", bban[i])
+                print code[i] "\t" name[i] "\t" length_[i] "\t" bban[i] "\t" bank[i] "\t" branch[i] "\t" example[i]
+            }
+        }' > iban-registry.txt
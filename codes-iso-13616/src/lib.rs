@@ -0,0 +1,432 @@
+/*!
+This package contains an implementation of the
+[ISO 13616](https://www.iso.org/standard/81090.html)
+International bank account number (IBAN) specification.
+
+The International Bank Account Number (IBAN) is an internationally agreed
+system of identifying bank accounts across national borders to facilitate the
+communication and processing of cross border transactions with a reduced risk
+of transcription errors. An IBAN is made up of three parts:
+
+1. Characters 1-2: The ISO 3166-1 alpha-2 code of the country in which the
+   account is held, or a prefix only present in the registry such as `XK`
+   for Kosovo.
+2. Characters 3-4: Two check digits calculated using ISO/IEC 7064 (MOD 97-10).
+3. Characters 5-: The Basic Bank Account Number (BBAN), the length and
+   structure of which is defined by each country and published in the
+   [IBAN Registry](https://www.swift.com/standards/data-standards/iban-international-bank-account-number)
+   maintained by SWIFT as the registration authority.
+
+An IBAN has an electronic format, with no spaces, and a printed format where
+the characters are separated into groups of four by a single space.
+
+# Example
+
+```rust
+use codes_iso_13616::Iban;
+use codes_iso_3166::part_1::CountryCode;
+use std::str::FromStr;
+
+let iban = Iban::from_str("GB29 NWBK 6016 1331 9268 19").unwrap();
+
+assert_eq!(iban.country_code(), Some(CountryCode::GB));
+assert_eq!(iban.check_digits(), 29);
+assert_eq!(iban.bban(), "NWBK60161331926819");
+assert_eq!(iban.bank_identifier(), Some("NWBK"));
+assert_eq!(iban.branch_identifier(), Some("601613"));
+
+assert_eq!(format!("{}", iban), "GB29NWBK60161331926819");
+assert_eq!(format!("{:#}", iban), "GB29 NWBK 6016 1331 9268 19");
+```
+
+The structure of IBANs for a country is available from the [registry]
+module.
+
+```rust
+use codes_iso_13616::registry::registry_entry_for;
+use codes_iso_3166::part_1::CountryCode;
+
+let entry = registry_entry_for(&CountryCode::DE).unwrap();
+
+assert_eq!(entry.iban_length(), 22);
+assert_eq!(entry.iban_example(), "DE89370400440532013000");
+```
+
+# Features
+
+By default only the `serde` feature is enabled.
+
+* `serde` - Enables serialization of the [Iban] type.
+
+*/
+
+#![warn(
+    unknown_lints,
+    // ---------- Stylistic
+    absolute_paths_not_starting_with_crate,
+    elided_lifetimes_in_paths,
+    explicit_outlives_requirements,
+    macro_use_extern_crate,
+    nonstandard_style, /* group */
+    noop_method_call,
+    rust_2018_idioms,
+    single_use_lifetimes,
+    trivial_casts,
+    trivial_numeric_casts,
+    // ---------- Future
+    future_incompatible, /* group */
+    rust_2021_compatibility, /* group */
+    // ---------- Public
+    missing_debug_implementations,
+    // missing_docs,
+    unreachable_pub,
+    // ---------- Unsafe
+    unsafe_code,
+    unsafe_op_in_unsafe_fn,
+    // ---------- Unused
+    unused, /* group */
+)]
+#![deny(
+    // ---------- Public
+    exported_private_dependencies,
+    private_interfaces,
+    private_bounds,
+    // ---------- Deprecated
+    anonymous_parameters,
+    bare_trait_objects,
+    ellipsis_inclusive_range_patterns,
+    // ---------- Unsafe
+    deref_nullptr,
+    drop_bounds,
+    dyn_drop,
+)]
+
+use codes_agency::{standardized_type, Agency, Standard};
+use codes_check_digits::iso_7064::{get_algorithm_instance, CheckDigitAlgorithm, IsoVariant};
+use codes_check_digits::Calculator;
+use codes_common::error::{invalid_character, invalid_format, invalid_length, unknown_value};
+use codes_common::{variable_length_code, Code, VariableLengthCode};
+use codes_iso_3166::part_1::CountryCode;
+use registry::{registry_entry_for_prefix, IbanPrefix, RegistryEntry};
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An instance of the `Standard` struct defined in the
+/// [`codes_agency`](https://docs.rs/codes-agency/latest/codes_agency/)
+/// package that describes the ISO-13616 specification.
+///
+pub const ISO_13616: Standard = Standard::new_with_long_ref(
+    Agency::ISO,
+    "13616",
+    "ISO 13616-1:2020",
+    "Financial services — International bank account number (IBAN) — Part 1: Structure of the IBAN",
+    "https://www.iso.org/standard/81090.html",
+)
+.with_edition("2020")
+.with_publication_date("2020-05")
+.with_registration_authority(Agency::SWIFT);
+
+///
+/// The ISO 13616 International Bank Account Number (IBAN).
+///
+/// An IBAN is only constructed if the country uses the IBAN, the length and
+/// structure of the BBAN match the entry for the country in the
+/// [registry], and the check digits are valid.
+///
+/// # Format
+///
+/// | Characters | Usage        | Alphabet      | Notes                       |
+/// | ---------- | ------------ | ------------- | --------------------------- |
+/// | 1..2       | Country Code | alphabetic    | ISO 3166-1 alpha-2, or `XK` |
+/// | 3..4       | Check Digits | digits        | ISO/IEC 7064 MOD 97-10      |
+/// | 5..        | BBAN         | alpha-numeric | up to 30, country specific  |
+///
+/// # Examples:
+///
+/// * `GB29 NWBK 6016 1331 9268 19` - United Kingdom
+/// * `DE89 3704 0044 0532 0130 00` - Germany
+/// * `FR14 2004 1010 0505 0001 3M02 606` - France
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Iban {
+    prefix: IbanPrefix,
+    check_digits: u8,
+    // Basic Bank Account Number (BBAN)
+    bban: String,
+}
+
+pub use codes_common::CodeParseError as IbanError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const TYPE_NAME: &str = "Iban";
+
+const ISO_MOD_97_10: CheckDigitAlgorithm = get_algorithm_instance(IsoVariant::Mod_97_10);
+
+impl Display for Iban {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let electronic = format!("{}{:02}{}", self.prefix, self.check_digits, self.bban);
+        if f.alternate() {
+            for (i, c) in electronic.chars().enumerate() {
+                if i > 0 && i % 4 == 0 {
+                    f.write_char(' ')?;
+                }
+                f.write_char(c)?;
+            }
+            Ok(())
+        } else {
+            f.write_str(&electronic)
+        }
+    }
+}
+
+impl From<Iban> for String {
+    fn from(v: Iban) -> String {
+        v.to_string()
+    }
+}
+
+impl TryFrom<String> for Iban {
+    type Error = IbanError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+impl FromStr for Iban {
+    type Err = IbanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // spaces are included in the printed form, see examples above.
+        let s = s.replace(' ', "").to_ascii_uppercase();
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(invalid_character(TYPE_NAME, c));
+        }
+        if s.len() < Self::min_length() || s.len() > Self::max_length() {
+            return Err(invalid_length(TYPE_NAME, s.len()));
+        }
+        let entry =
+            registry_entry_for_prefix(&s[..2]).ok_or_else(|| unknown_value(TYPE_NAME, &s[..2]))?;
+        if s.len() != entry.iban_length() {
+            return Err(invalid_length(TYPE_NAME, s.len()));
+        }
+        let check_digits = u8::from_str(&s[2..4]).map_err(|_| invalid_format(TYPE_NAME, &s))?;
+        let bban = &s[4..];
+        if !entry.is_valid_bban(bban) {
+            return Err(invalid_format(TYPE_NAME, &s));
+        }
+        // the check digits are calculated with the country code and check
+        // digits moved to the end of the string.
+        ISO_MOD_97_10.validate(format!("{}{}", bban, &s[..4]))?;
+        Ok(Self {
+            prefix: entry.prefix(),
+            check_digits,
+            bban: bban.to_string(),
+        })
+    }
+}
+
+impl Code<String> for Iban {}
+
+variable_length_code!(Iban, 15, 34);
+
+standardized_type!(Iban, ISO_13616);
+
+impl Iban {
+    ///
+    /// Construct a new IBAN from country code and BBAN. This will check the
+    /// BBAN against the registry entry for the country, and calculate the
+    /// IBAN check digits.
+    ///
+    pub fn new(country: CountryCode, bban: &str) -> Result<Self, IbanError> {
+        let entry = registry_entry_for_prefix(country.alpha_2_code())
+            .ok_or_else(|| unknown_value(TYPE_NAME, country.to_string()))?;
+        if !entry.is_valid_bban(bban) {
+            return Err(invalid_format(TYPE_NAME, bban));
+        }
+        let check_digits = ISO_MOD_97_10.calculate(&format!("{}{}", bban, country))?;
+        Ok(Self {
+            prefix: entry.prefix(),
+            check_digits: check_digits.value() as u8,
+            bban: bban.to_string(),
+        })
+    }
+
+    ///
+    /// Return the prefix, usually the country code, of this IBAN.
+    ///
+    pub fn prefix(&self) -> IbanPrefix {
+        self.prefix
+    }
+
+    ///
+    /// Return the country code of this IBAN, or `None` if the prefix is not
+    /// an ISO 3166-1 country code, such as `XK` for Kosovo.
+    ///
+    pub fn country_code(&self) -> Option<CountryCode> {
+        self.prefix.country_code()
+    }
+
+    ///
+    /// Return the check digits of this IBAN.
+    ///
+    pub fn check_digits(&self) -> u8 {
+        self.check_digits
+    }
+
+    ///
+    /// Return the Basic Bank Account Number (BBAN) portion of this IBAN.
+    ///
+    pub fn bban(&self) -> &str {
+        &self.bban
+    }
+
+    ///
+    /// Return the registry entry for the country of this IBAN.
+    ///
+    pub fn registry_entry(&self) -> &'static RegistryEntry {
+        // the entry was checked on construction.
+        registry_entry_for_prefix(self.prefix.as_str()).unwrap()
+    }
+
+    ///
+    /// Return the bank identifier portion of the BBAN, if the registry
+    /// defines one for the country of this IBAN.
+    ///
+    pub fn bank_identifier(&self) -> Option<&str> {
+        self.registry_entry()
+            .bank_identifier_range()
+            .map(|range| &self.bban[range])
+    }
+
+    ///
+    /// Return the branch identifier portion of the BBAN, if the registry
+    /// defines one for the country of this IBAN.
+    ///
+    pub fn branch_identifier(&self) -> Option<&str> {
+        self.registry_entry()
+            .branch_identifier_range()
+            .map(|range| &self.bban[range])
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod registry;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{registry_entry_for, ALL_COUNTRIES};
+
+    #[test]
+    fn test_registry_examples() {
+        for country in ALL_COUNTRIES {
+            let entry = registry_entry_for(&country).unwrap();
+            let iban = Iban::from_str(entry.iban_example()).unwrap();
+            assert_eq!(iban.country_code(), Some(country));
+            assert_eq!(iban.to_string(), entry.iban_example());
+        }
+    }
+
+    #[test]
+    fn test_registered_prefix() {
+        let iban = Iban::from_str("XK05 1212 0123 4567 8906").unwrap();
+        assert_eq!(iban.prefix(), IbanPrefix::Registered("XK"));
+        assert_eq!(iban.country_code(), None);
+        assert_eq!(iban.bank_identifier(), Some("12"));
+        assert_eq!(iban.branch_identifier(), Some("12"));
+        assert_eq!(iban.to_string(), "XK051212012345678906");
+
+        assert!(Iban::from_str("XK06 1212 0123 4567 8906").is_err());
+        assert!(!ALL_COUNTRIES
+            .iter()
+            .any(|country| country.alpha_2_code() == "XK"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let iban = Iban::from_str("GB29NWBK60161331926819").unwrap();
+        let json = serde_json::to_string(&iban).unwrap();
+        assert_eq!(json, r#""GB29NWBK60161331926819""#);
+        assert_eq!(serde_json::from_str::<Iban>(&json).unwrap(), iban);
+        assert!(serde_json::from_str::<Iban>(r#""GB28NWBK60161331926819""#).is_err());
+    }
+
+    #[test]
+    fn test_display_formatting() {
+        let iban = Iban::from_str("FR1420041010050500013M02606").unwrap();
+        assert_eq!(format!("{}", iban), "FR1420041010050500013M02606");
+        assert_eq!(format!("{:#}", iban), "FR14 2004 1010 0505 0001 3M02 606");
+    }
+
+    #[test]
+    fn test_from_str_printed() {
+        let iban = Iban::from_str("fr14 2004 1010 0505 0001 3m02 606").unwrap();
+        assert_eq!(iban.to_string(), "FR1420041010050500013M02606");
+    }
+
+    #[test]
+    fn test_new() {
+        let iban = Iban::new(CountryCode::DE, "370400440532013000").unwrap();
+        assert_eq!(iban.to_string(), "DE89370400440532013000");
+
+        let iban = Iban::new(CountryCode::NO, "86011117947").unwrap();
+        assert_eq!(format!("{:#}", iban), "NO93 8601 1117 947");
+
+        assert!(Iban::new(CountryCode::DE, "37040044053201300").is_err());
+        assert!(Iban::new(CountryCode::US, "370400440532013000").is_err());
+    }
+
+    #[test]
+    fn test_identifiers() {
+        let iban = Iban::from_str("IT60X0542811101000000123456").unwrap();
+        assert_eq!(iban.bank_identifier(), Some("05428"));
+        assert_eq!(iban.branch_identifier(), Some("11101"));
+
+        let iban = Iban::from_str("DE89370400440532013000").unwrap();
+        assert_eq!(iban.bank_identifier(), Some("37040044"));
+        assert_eq!(iban.branch_identifier(), None);
+
+        let iban = Iban::from_str("PL61109010140000071219812874").unwrap();
+        assert_eq!(iban.bank_identifier(), None);
+        assert_eq!(iban.branch_identifier(), Some("10901014"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        // check digits
+        assert!(Iban::from_str("GB28NWBK60161331926819").is_err());
+        // transposed characters
+        assert!(Iban::from_str("GB29NWBK61061331926819").is_err());
+        // length
+        assert!(Iban::from_str("GB29NWBK6016133192681").is_err());
+        // BBAN structure, numeric bank identifier
+        assert!(Iban::from_str("GB291234601613319268").is_err());
+        // country does not use the IBAN
+        assert!(Iban::from_str("US29NWBK60161331926819").is_err());
+        // not a country
+        assert!(Iban::from_str("ZZ29NWBK60161331926819").is_err());
+        assert!(Iban::from_str("GB29-NWBK-6016-1331-9268-19").is_err());
+    }
+}
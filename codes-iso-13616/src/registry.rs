@@ -0,0 +1,280 @@
+/*!
+Provides the per-country structure of IBANs as described by the SWIFT IBAN
+Registry.
+
+Each country that uses the IBAN has a [RegistryEntry] which describes the
+length of the IBAN, the structure of the Basic Bank Account Number (BBAN), and
+where the bank and branch identifiers occur within the BBAN.
+
+The registry also includes prefixes that are not ISO 3166-1 country codes,
+currently only `XK` for Kosovo. These are not returned by [registry_entry_for]
+or included in [ALL_COUNTRIES], but are available from
+[registry_entry_for_prefix].
+
+# Example
+
+```rust
+use codes_iso_13616::registry::{registry_entry_for, registry_entry_for_prefix, CharacterClass};
+use codes_iso_3166::part_1::CountryCode;
+
+let entry = registry_entry_for(&CountryCode::GB).unwrap();
+
+assert_eq!(entry.iban_length(), 22);
+assert_eq!(entry.bban_length(), 18);
+assert_eq!(entry.bban_structure()[0].class(), CharacterClass::Alphabetic);
+assert_eq!(entry.bank_identifier_range(), Some(0..4));
+assert_eq!(entry.branch_identifier_range(), Some(4..10));
+assert!(entry.is_valid_bban("NWBK60161331926819"));
+
+let entry = registry_entry_for_prefix("XK").unwrap();
+
+assert_eq!(entry.country_code(), None);
+assert_eq!(entry.iban_length(), 20);
+```
+
+*/
+
+use codes_iso_3166::part_1::CountryCode;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The two character prefix of an IBAN, and of an entry in the registry.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IbanPrefix {
+    /// An ISO 3166-1 alpha-2 country code.
+    Country(CountryCode),
+    /// A prefix only present in the IBAN registry, such as `XK` for Kosovo
+    /// which is not (yet) assigned in ISO 3166-1.
+    Registered(&'static str),
+}
+
+///
+/// The class of characters allowed in a [BbanSegment], these correspond to
+/// the `n`, `a`, and `c` characters used in the registry.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CharacterClass {
+    /// Digits, `0..=9`.
+    Numeric,
+    /// Upper case letters, `A..=Z`.
+    Alphabetic,
+    /// Upper and lower case letters and digits.
+    Alphanumeric,
+}
+
+///
+/// A fixed length segment of a BBAN with all characters from a single
+/// [CharacterClass].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BbanSegment {
+    length: usize,
+    class: CharacterClass,
+}
+
+///
+/// The structure of IBANs for a single country.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegistryEntry {
+    prefix: IbanPrefix,
+    country_name: &'static str,
+    iban_length: usize,
+    bban_structure: &'static [BbanSegment],
+    bank_identifier: Option<(usize, usize)>,
+    branch_identifier: Option<(usize, usize)>,
+    iban_example: &'static str,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for IbanPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl IbanPrefix {
+    ///
+    /// Return the two character string form of this prefix.
+    ///
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Country(country_code) => country_code.alpha_2_code(),
+            Self::Registered(prefix) => prefix,
+        }
+    }
+
+    ///
+    /// Return the country code for this prefix, or `None` if the prefix is
+    /// only present in the registry.
+    ///
+    pub const fn country_code(&self) -> Option<CountryCode> {
+        match self {
+            Self::Country(country_code) => Some(*country_code),
+            Self::Registered(_) => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for CharacterClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl CharacterClass {
+    ///
+    /// Return the character used in the registry for this class.
+    ///
+    pub const fn code(&self) -> char {
+        match self {
+            Self::Numeric => 'n',
+            Self::Alphabetic => 'a',
+            Self::Alphanumeric => 'c',
+        }
+    }
+
+    ///
+    /// Returns `true` if `c` is a member of this class, else `false`.
+    ///
+    pub const fn contains(&self, c: char) -> bool {
+        match self {
+            Self::Numeric => c.is_ascii_digit(),
+            Self::Alphabetic => c.is_ascii_uppercase(),
+            Self::Alphanumeric => c.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for BbanSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}!{}", self.length, self.class)
+    }
+}
+
+impl BbanSegment {
+    const fn new(length: usize, class: CharacterClass) -> Self {
+        Self { length, class }
+    }
+
+    ///
+    /// Return the number of characters in this segment.
+    ///
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+
+    ///
+    /// Return the class of characters allowed in this segment.
+    ///
+    pub const fn class(&self) -> CharacterClass {
+        self.class
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl RegistryEntry {
+    ///
+    /// Return the IBAN prefix this entry describes.
+    ///
+    pub const fn prefix(&self) -> IbanPrefix {
+        self.prefix
+    }
+
+    ///
+    /// Return the country this entry describes, or `None` if the prefix is
+    /// not an ISO 3166-1 country code.
+    ///
+    pub const fn country_code(&self) -> Option<CountryCode> {
+        self.prefix.country_code()
+    }
+
+    ///
+    /// Return the name of the country as it appears in the registry.
+    ///
+    pub const fn country_name(&self) -> &'static str {
+        self.country_name
+    }
+
+    ///
+    /// Return the total length of an IBAN for this country.
+    ///
+    pub const fn iban_length(&self) -> usize {
+        self.iban_length
+    }
+
+    ///
+    /// Return the length of the BBAN, the IBAN without the country code and
+    /// check digits.
+    ///
+    pub const fn bban_length(&self) -> usize {
+        self.iban_length - 4
+    }
+
+    ///
+    /// Return the segments that make up the BBAN.
+    ///
+    pub const fn bban_structure(&self) -> &'static [BbanSegment] {
+        self.bban_structure
+    }
+
+    ///
+    /// Return the range of the bank identifier within the BBAN, if the
+    /// registry defines one.
+    ///
+    pub fn bank_identifier_range(&self) -> Option<Range<usize>> {
+        self.bank_identifier.map(|(start, end)| start..end)
+    }
+
+    ///
+    /// Return the range of the branch identifier within the BBAN, if the
+    /// registry defines one.
+    ///
+    pub fn branch_identifier_range(&self) -> Option<Range<usize>> {
+        self.branch_identifier.map(|(start, end)| start..end)
+    }
+
+    ///
+    /// Return the example IBAN, in electronic format, from the registry.
+    ///
+    pub const fn iban_example(&self) -> &'static str {
+        self.iban_example
+    }
+
+    ///
+    /// Returns `true` if `bban` has the length and structure required by
+    /// this entry, else `false`.
+    ///
+    pub fn is_valid_bban(&self, bban: &str) -> bool {
+        let mut chars = bban.chars();
+        bban.len() == self.bban_length()
+            && self.bban_structure.iter().all(|segment| {
+                chars
+                    .by_ref()
+                    .take(segment.length)
+                    .all(|c| segment.class.contains(c))
+            })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+//
+// The rest of this file is generated by the package build script.
+//
+// ------------------------------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
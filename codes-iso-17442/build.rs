@@ -0,0 +1,32 @@
+use codes_common::build::csv::process_csv_input;
+use codes_common::build::{
+    default_finalize_for, make_default_renderer, process, Data, DataRow, SimpleData,
+};
+use codes_common::insert_field;
+use csv::StringRecord;
+
+const TYPE_NAME: &str = "LocalOperatingUnit";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    process(
+        || Ok(SimpleData::new(TYPE_NAME)),
+        |data| process_csv_input(data, "local-operating-units.csv", process_input_row),
+        default_finalize_for,
+        make_default_renderer("lou._rs", "lou.rs"),
+    )
+}
+
+fn process_input_row(
+    record: StringRecord,
+    row: &mut DataRow,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let id = record.get(0).unwrap().to_string();
+
+    // ID field
+    insert_field!(id.clone() => row, "prefix");
+
+    // Required fields
+    insert_field!(record, row, 1 => "name", 2 => "country_code");
+
+    Ok(id)
+}
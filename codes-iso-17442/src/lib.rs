@@ -42,11 +42,24 @@ assert_eq!(lei.entity(), "GD8L7GG84979J5");
 assert_eq!(lei.check_digits(), "16");
 ```
 
+An LEI may also be constructed from the LOU prefix and entity identifier, in
+which case the check digits are calculated.
+
+```rust
+use codes_iso_17442::LegalEntityId;
+
+let lei = LegalEntityId::new("2138", "00WSGIIZCXF1P5").unwrap();
+
+assert_eq!(lei.to_string(), "213800WSGIIZCXF1P572");
+```
+
 # Features
 
-By default only the `serde` feature is enabled.
+By default only the `serde` and `lou` features are enabled.
 
 * `serde` - Enables serialization of the [LegalEntityId] type.
+* `lou` - Adds the [lou] module containing the table of Local Operating
+  Units, and the [LegalEntityId::local_operating_unit_details] method.
 * `url` - Enables the conversion between LEI and URL (URN) forms.
 
 */
@@ -80,7 +93,8 @@ By default only the `serde` feature is enabled.
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -94,7 +108,7 @@ By default only the `serde` feature is enabled.
 use codes_agency::{standardized_type, Agency, Standard};
 use codes_check_digits::iso_7064::{get_algorithm_instance, CheckDigitAlgorithm, IsoVariant};
 use codes_check_digits::Calculator;
-use codes_common::error::{invalid_format, invalid_length};
+use codes_common::error::{invalid_character, invalid_format, invalid_length};
 use codes_common::{code_as_str, code_impl, fixed_length_code, FixedLengthCode};
 use std::str::FromStr;
 
@@ -116,7 +130,10 @@ pub const ISO_17442: Standard = Standard::new_with_long_ref(
     "ISO 17442-1:2020",
     "Financial services — Legal entity identifier (LEI) — Part 1: Assignment",
     "https://www.iso.org/standard/78829.html",
-);
+)
+.with_edition("2020")
+.with_publication_date("2020-08")
+.with_registration_authority(Agency::GLEIF);
 
 /// The formatted Legal Entity Identifier (LEI) is formatted as a
 /// 20-character, alpha-numeric code based on the ISO 17442 standard developed
@@ -225,6 +242,29 @@ fixed_length_code!(LegalEntityId, 20);
 standardized_type!(LegalEntityId, ISO_17442);
 
 impl LegalEntityId {
+    ///
+    /// Construct a new LEI from the four character prefix allocated to the
+    /// Local Operating Unit (LOU) and the fourteen character entity
+    /// identifier, calculating the check digits.
+    ///
+    pub fn new(lou_prefix: &str, entity: &str) -> Result<Self, LegalEntityIdError> {
+        if lou_prefix.len() != 4 {
+            Err(invalid_length("LegalEntityId", lou_prefix.len()))
+        } else if entity.len() != 14 {
+            Err(invalid_length("LegalEntityId", entity.len()))
+        } else if let Some(c) = lou_prefix
+            .chars()
+            .chain(entity.chars())
+            .find(|c| !(c.is_ascii_uppercase() || c.is_ascii_digit()))
+        {
+            Err(invalid_character("LegalEntityId", c))
+        } else {
+            let s = format!("{}{}", lou_prefix, entity);
+            let check_digits = ISO_MOD_97_10.calculate(&s)?;
+            Ok(LegalEntityId(format!("{}{}", s, check_digits)))
+        }
+    }
+
     ///
     /// Return the portion of the LEI that corresponds to the Local Operating
     /// Unit (LOU) accredited by GLEIF to issue entity identifiers.
@@ -233,6 +273,15 @@ impl LegalEntityId {
         &self.0[..4]
     }
 
+    ///
+    /// Return the details of the Local Operating Unit (LOU) that issued this
+    /// LEI, or `None` if the prefix is not known.
+    ///
+    #[cfg(feature = "lou")]
+    pub fn local_operating_unit_details(&self) -> Option<&'static lou::LocalOperatingUnit> {
+        lou::local_operating_unit_for(self.local_operating_unit())
+    }
+
     ///
     /// Return the portion of the LEI that corresponds to the Entity Identifier
     /// assigned by a GLEIF accredited Local Operating Unit (LOU).
@@ -249,6 +298,13 @@ impl LegalEntityId {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "lou")]
+pub mod lou;
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
@@ -286,6 +342,32 @@ mod tests {
         assert_eq!(lei.check_digits(), "16");
     }
 
+    #[test]
+    fn test_new() {
+        let lei = LegalEntityId::new("5493", "0084UKLVMY22DS").unwrap();
+        assert_eq!(lei.as_str(), "54930084UKLVMY22DS16");
+        let lei = LegalEntityId::new("YZ83", "GD8L7GG84979J5").unwrap();
+        assert_eq!(lei.check_digits(), "16");
+
+        assert!(LegalEntityId::new("549", "0084UKLVMY22DS").is_err());
+        assert!(LegalEntityId::new("5493", "0084UKLVMY22D").is_err());
+        assert!(LegalEntityId::new("5493", "0084ukLVMY22DS").is_err());
+    }
+
+    #[cfg(feature = "lou")]
+    #[test]
+    fn test_local_operating_unit_details() {
+        use codes_iso_3166::part_1::CountryCode;
+
+        let lei = LegalEntityId::from_str("213800WSGIIZCXF1P572").unwrap();
+        let lou = lei.local_operating_unit_details().unwrap();
+        assert_eq!(lou.prefix(), "2138");
+        assert_eq!(lou.country_code(), CountryCode::GB);
+
+        let lei = LegalEntityId::from_str("YZ83GD8L7GG84979J516").unwrap();
+        assert!(lei.local_operating_unit_details().is_none());
+    }
+
     #[cfg(feature = "url")]
     #[test]
     fn test_lei_to_url() {
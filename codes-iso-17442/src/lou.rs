@@ -0,0 +1,72 @@
+/*!
+Provides the Local Operating Units (LOUs) accredited by GLEIF to issue LEIs,
+identified by the four character prefix allocated to each.
+
+Note that this is not a complete list of allocated prefixes, and that LEIs
+issued before the allocation of prefixes in 2012 do not start with the prefix
+of any LOU.
+
+# Example
+
+```rust
+use codes_iso_17442::lou::local_operating_unit_for;
+use codes_iso_3166::part_1::CountryCode;
+
+let lou = local_operating_unit_for("2138").unwrap();
+
+assert_eq!(lou.name(), "London Stock Exchange LEI Limited");
+assert_eq!(lou.country_code(), CountryCode::GB);
+```
+
+*/
+
+use codes_iso_3166::part_1::CountryCode;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A Local Operating Unit (LOU) accredited by GLEIF to issue LEIs.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LocalOperatingUnit {
+    prefix: &'static str,
+    name: &'static str,
+    country_code: CountryCode,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl LocalOperatingUnit {
+    ///
+    /// Return the four character prefix allocated to this LOU.
+    ///
+    pub const fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    ///
+    /// Return the name of this LOU.
+    ///
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    ///
+    /// Return the country in which this LOU is based.
+    ///
+    pub const fn country_code(&self) -> CountryCode {
+        self.country_code
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+//
+// The rest of this file is generated by the package build script.
+//
+// ------------------------------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/lou.rs"));
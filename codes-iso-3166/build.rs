@@ -4,7 +4,11 @@ use codes_common::build::{
 };
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{fs::File, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs::File,
+    str::FromStr,
+};
 use tera::{Map, Value};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -16,14 +20,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .and_then(process_part_1_name_data)
         },
         filter_finalize_part_1,
-        make_default_renderer("part_1._rs", "part_1.rs"),
+        |ctx| {
+            make_default_renderer("part_1._rs", "part_1.rs")(ctx).and_then(make_default_renderer(
+                "part_1_indices._rs",
+                "part_1_indices.rs",
+            ))
+        },
     )?;
 
     process(
         || Ok(SimpleData::new("SubdivisionCode")),
         |data| process_part_2_data(data).and_then(process_part_2_name_data),
-        default_finalize_for,
-        make_default_renderer("part_2._rs", "part_2.rs"),
+        finalize_part_2,
+        |ctx| {
+            make_default_renderer("part_2._rs", "part_2.rs")(ctx).and_then(make_default_renderer(
+                "part_2_indices._rs",
+                "part_2_indices.rs",
+            ))
+        },
     )?;
 
     process(
@@ -48,6 +62,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         process_part_2_territory_data,
         default_finalize_for,
         make_default_renderer("territories._rs", "territories.rs"),
+    )?;
+
+    process(
+        || Ok(SimpleData::new("FormerCountryCode")),
+        process_part_3_data,
+        default_finalize_for,
+        make_default_renderer("part_3._rs", "part_3.rs"),
     )
 }
 
@@ -190,11 +211,17 @@ fn process_part_1_name_data(
         let code = record.get(0).unwrap();
         let row = data.get_mut(code).unwrap();
 
+        // Names for a code's former assignment are listed under its former alpha-3 code.
+        if row.get("alpha_3_code").and_then(Value::as_str) != record.get(1) {
+            continue;
+        }
+
+        let language_alpha_3_code = record.get(4).unwrap();
+
         // AN/ANT has no administrative language
         if let Some(admin_language) = row.get("administrative_language") {
             let admin_language = admin_language.as_str().unwrap();
 
-            let language_alpha_3_code = record.get(4).unwrap();
             if language_alpha_3_code == admin_language {
                 let name = record.get(5).unwrap().to_string();
                 if !name.is_empty() {
@@ -207,6 +234,13 @@ fn process_part_1_name_data(
                 }
             }
         }
+
+        for (key, index) in [("short_names", 5), ("full_names", 7)] {
+            let name = record.get(index).unwrap();
+            if !name.is_empty() {
+                insert_localized_name(row, key, language_alpha_3_code, localized_name(name, None));
+            }
+        }
     }
 
     Ok(data)
@@ -216,7 +250,56 @@ pub fn filter_finalize_part_1(
     mut data: SimpleData,
 ) -> Result<tera::Context, Box<dyn std::error::Error>> {
     data.retain(|_, v| v.contains_key("alpha_2_code"));
-    default_finalize_for(data)
+
+    let mut indices: Map<String, Value> = Default::default();
+    for field in ["alpha_3_code", "former_alpha_3_code"] {
+        indices.insert(
+            field.to_string(),
+            make_part_1_index(&data, field, |v| v.as_str().unwrap().to_string()),
+        );
+    }
+    indices.insert(
+        "numeric_code".to_string(),
+        make_part_1_index(&data, "numeric_code", |v| v.to_string()),
+    );
+    indices.insert(
+        "short_name".to_string(),
+        make_part_1_index(&data, "short_name", |v| v.as_str().unwrap().to_uppercase()),
+    );
+
+    let mut ctx = default_finalize_for(data)?;
+    ctx.insert("indices", &Value::Object(indices));
+    Ok(ctx)
+}
+
+// Returns a list of `[key, id]` pairs for the values of `field`. Some reserved
+// codes share a name or numeric code with an assigned one, as index keys have
+// to be unique officially assigned codes take precedence, after that the first
+// code in sort order is used.
+fn make_part_1_index<F>(data: &SimpleData, field: &str, key_fn: F) -> Value
+where
+    F: Fn(&Value) -> String,
+{
+    let mut rows: Vec<(&String, &Map<String, Value>)> = data.rows().iter().collect();
+    rows.sort_by_key(|(id, row)| {
+        (
+            row.get("status").and_then(Value::as_str) != Some("Status::OfficiallyAssigned"),
+            id.to_string(),
+        )
+    });
+
+    let mut seen: HashSet<String> = Default::default();
+    let mut index: Vec<Value> = Default::default();
+    for (id, row) in rows {
+        if let Some(value) = row.get(field) {
+            let key = key_fn(value);
+            if seen.insert(key.clone()) {
+                index.push(Value::Array(vec![key.into(), id.to_string().into()]));
+            }
+        }
+    }
+    index.sort_by(|lhs, rhs| lhs[1].as_str().cmp(&rhs[1].as_str()));
+    Value::Array(index)
 }
 
 fn process_part_2_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::error::Error>> {
@@ -270,6 +353,42 @@ lazy_static! {
     .unwrap();
 }
 
+fn finalize_part_2(data: SimpleData) -> Result<tera::Context, Box<dyn std::error::Error>> {
+    let mut indices: Map<String, Value> = Default::default();
+    for field in [
+        "country_alpha_2_code",
+        "parent_subdivision_code",
+        "category_code",
+    ] {
+        indices.insert(field.to_string(), make_part_2_index(&data, field));
+    }
+
+    let mut ctx = default_finalize_for(data)?;
+    ctx.insert("indices", &Value::Object(indices));
+    Ok(ctx)
+}
+
+// Returns a list of `[key, [id, ...]]` pairs grouping subdivisions by the value
+// of `field`, subdivision identifiers are in sort order within each group.
+fn make_part_2_index(data: &SimpleData, field: &str) -> Value {
+    let mut index: BTreeMap<String, Vec<Value>> = Default::default();
+    for (id, row) in data.rows() {
+        if let Some(value) = row.get(field) {
+            let key = match value {
+                Value::String(s) => s.to_string(),
+                _ => value.to_string(),
+            };
+            index.entry(key).or_default().push(id.to_string().into());
+        }
+    }
+    Value::Array(
+        index
+            .into_iter()
+            .map(|(key, ids)| Value::Array(vec![key.into(), Value::Array(ids)]))
+            .collect(),
+    )
+}
+
 fn process_part_2_name_data(
     mut data: SimpleData,
 ) -> Result<SimpleData, Box<dyn std::error::Error>> {
@@ -303,15 +422,22 @@ fn process_part_2_name_data(
         } else {
             name.to_string()
         };
+        let mut localized = localized_name(&name, Some(record.get(9).unwrap()));
         row.insert("name".to_string(), name.into());
 
         let name_local_variation = record.get(8).unwrap().to_string();
         if !name_local_variation.is_empty() {
+            localized.insert(
+                "local_variation".to_string(),
+                Value::String(name_local_variation.clone()),
+            );
             row.insert(
                 "name_local_variation".to_string(),
                 Value::String(name_local_variation),
             );
         }
+
+        insert_localized_name(row, "names", record.get(6).unwrap(), localized);
     }
 
     Ok(data)
@@ -408,6 +534,103 @@ fn process_part_2_territory_data(
     Ok(data)
 }
 
+fn process_part_3_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::error::Error>> {
+    let file_name = input_file_name("former-country-codes.csv");
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(File::open(file_name)?);
+
+    for result in rdr.records() {
+        let record = result?;
+
+        let mut row: Map<String, Value> = Default::default();
+
+        let id = record.get(0).unwrap().to_string();
+        row.insert("code".to_string(), Value::String(id.clone()));
+        row.insert(
+            "name".to_string(),
+            Value::String(record.get(1).unwrap().to_string()),
+        );
+        row.insert(
+            "alpha_2_code".to_string(),
+            Value::String(record.get(2).unwrap().to_string()),
+        );
+        row.insert(
+            "alpha_3_code".to_string(),
+            Value::String(record.get(3).unwrap().to_string()),
+        );
+
+        let numeric_code = record.get(4).unwrap();
+        if !numeric_code.is_empty() {
+            let numeric_code = u16::from_str(numeric_code)?;
+            row.insert(
+                "numeric_code".to_string(),
+                Value::Number(numeric_code.into()),
+            );
+        }
+
+        for (key, index) in [("valid_from", 5), ("valid_until", 6)] {
+            let year = u16::from_str(record.get(index).unwrap())?;
+            row.insert(key.to_string(), Value::Number(year.into()));
+        }
+
+        row.insert(
+            "successors".to_string(),
+            Value::Array(
+                record
+                    .get(7)
+                    .unwrap()
+                    .split_whitespace()
+                    .map(|code| Value::String(code.to_string()))
+                    .collect(),
+            ),
+        );
+
+        data.insert_row(&id, row);
+    }
+
+    Ok(data)
+}
+
+fn localized_name(name: &str, romanization_system: Option<&str>) -> Map<String, Value> {
+    let mut localized: Map<String, Value> = Default::default();
+    localized.insert("name".to_string(), name.into());
+    if let Some(romanization_system) = romanization_system.filter(|s| !s.is_empty()) {
+        localized.insert(
+            "romanization_system".to_string(),
+            romanization_system.into(),
+        );
+    }
+    localized
+}
+
+// Names are stored in `row[key][language]` as a list, as there may be more
+// than one romanized form of a name in a given language.
+fn insert_localized_name(
+    row: &mut Map<String, Value>,
+    key: &str,
+    language_alpha_3_code: &str,
+    localized: Map<String, Value>,
+) {
+    // See https://www.iso.org/obp/ui/#iso:code:3166:KM
+    if language_alpha_3_code == "002" {
+        return;
+    }
+    let names = row
+        .entry(key)
+        .or_insert_with(|| Value::Object(Default::default()))
+        .as_object_mut()
+        .unwrap();
+    names
+        .entry(language_alpha_3_code)
+        .or_insert_with(|| Value::Array(Default::default()))
+        .as_array_mut()
+        .unwrap()
+        .push(Value::Object(localized));
+}
+
 fn status_from_standard_string(s: &str) -> String {
     match s {
         "officially-assigned" => "Status::OfficiallyAssigned".to_string(),
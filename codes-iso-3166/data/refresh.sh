@@ -0,0 +1,32 @@
+#!/usr/bin/env sh
+
+# The file `former-country-codes.csv` lists the ISO 3166-3 codes for formerly
+# used country names and is maintained by hand from the ISO 3166-3 code list on
+# the ISO Online Browsing Platform (https://www.iso.org/obp/ui/#search).
+#
+# As the ISO tables are not available for download this script refreshes the
+# numeric codes from the Debian iso-codes project, keeping the existing value
+# where iso-codes has no numeric code; iso-codes does not include the numeric
+# codes of BQAQ, PZPA, and SKIN. A local copy of `iso_3166-3.json` may be
+# passed as the only argument, otherwise it is downloaded.
+
+ISO_CODES="https://salsa.debian.org/iso-codes-team/iso-codes/-/raw/main/data/iso_3166-3.json"
+SOURCE=${1:-iso_3166-3.json}
+
+if [ ! -f "${SOURCE}" ]; then
+    curl -o "${SOURCE}" "${ISO_CODES}" || exit 1
+fi
+
+jq -r '.["3166-3"][] | select(.numeric) | "\(.alpha_4)\t\(.numeric)"' "${SOURCE}" > numeric-codes.tsv
+
+awk -F '","' -v OFS='","' '
+    FNR == NR { split($0, pair, "\t"); numeric[pair[1]] = pair[2]; next }
+    FNR == 1 { print; next }
+    {
+        code = substr($1, 2)
+        if (code in numeric) $5 = numeric[code]
+        print
+    }' numeric-codes.tsv former-country-codes.csv > former-country-codes.csv.new
+
+mv former-country-codes.csv.new former-country-codes.csv
+rm numeric-codes.tsv
@@ -1,6 +1,6 @@
 /*!
 This package contains an implementation of the [ISO
-3166](https://www.iso.org/iso-3166-country-codes.html), parts 1 and 2,
+3166](https://www.iso.org/iso-3166-country-codes.html), parts 1, 2, and 3,
 standard.
 
 The purpose of ISO 3166 is to define internationally recognized codes of
@@ -30,26 +30,57 @@ assert_eq!(country.alpha_2_code(), "AG");
 assert_eq!(country.short_name(), "Antigua and Barbuda");
 ```
 
+Sets of codes, and maps keyed by codes, may be created using the compact
+`CodeSet` and `CodeMap` collections from the `codes-common` package, which can
+also be `static` values.
+
+```rust
+use codes_common::{code_set, collections::CodeSet};
+use codes_iso_3166::part_1::CountryCode;
+
+static NORDIC: CodeSet<CountryCode> = code_set![
+    CountryCode::DK,
+    CountryCode::FI,
+    CountryCode::IS,
+    CountryCode::NO,
+    CountryCode::SE,
+];
+
+assert!(NORDIC.contains(&CountryCode::IS));
+assert!(!NORDIC.contains(&CountryCode::DE));
+```
+
 # Features
 
 By default only the `serde` feature is enabled, and [part_1] two-letter
 language codes.
 
-* `serde` - Enables serialization of the different Language Code types.
-* `alpha_3_code` - Adds the `CountryCode::alpha_3_code` method.
-* `numeric_code` - Adds the `CountryCode::numeric_code` method.
+* `serde` - Enables serialization of the different Country Code types, as
+  their code strings; for example `SubdivisionCode::US_CA` as `"US-CA"`.
+* `alpha_3_code` - Adds the `CountryCode::alpha_3_code` method, with `serde` and
+  `indices` also adds the `part_1::alpha_3` module for use with
+  `#[serde(with = "...")]`.
+* `numeric_code` - Adds the `CountryCode::numeric_code` method, with `serde` and
+  `indices` also adds the `part_1::numeric` module for use with
+  `#[serde(with = "...")]`.
 * `independent` - Adds the `CountryCode::independent` method.
 * `status` - Adds the `CountryCode::status` method.
 * `full_name` - Adds the `CountryCode::full_name` method.
 * `local_names` - Adds the `CountryCode::local_short_name` and
   `CountryCode::local_full_name` methods.
 * `languages`  - Adds the `CountryCode::administrative_language` and `CountryCode::languages` methods (requires package `codes-iso-639`).
+  Also adds the `CountryCode::short_name_in` and `CountryCode::full_name_in` methods
+  returning [LocalizedName]s.
 * `formerly` - Adds the `CountryCode::former_short_name` and
   `CountryCode::former_alpha_3_code` methods.
 * `part_2` - Adds the corresponding module and `SubdivisionCode`.
   * `categories` - Adds the `SubdivisionCode::category_code` method and `SubdivisionCategoryCode` type.
   * `territories` - Adds the `TerritoryCode` type.
-  * `languages` - Adds the `SubdivisionCode::name_language` method.
+  * `languages` - Adds the `SubdivisionCode::name_language` and
+    `SubdivisionCode::subdivision_name_in` methods.
+* `part_3` - Adds the corresponding module and `FormerCountryCode`.
+* `indices` - Adds the `indices` modules to part 1 and 2 for lookup by secondary codes
+  and names.
 
 Note that the method `CountryCode::local_full_name` requires both
 `local_names` and `full_name` features.
@@ -85,7 +116,8 @@ Note that the method `CountryCode::local_full_name` requires both
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -96,12 +128,66 @@ Note that the method `CountryCode::local_full_name` requires both
     dyn_drop,
 )]
 
+#[cfg(feature = "languages")]
+use codes_iso_639::part_3::LanguageCode;
+#[cfg(feature = "languages")]
+use std::fmt::Display;
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
 pub use codes_common::CodeParseError as CountryCodeError;
 
+///
+/// A name for a country or subdivision in a specific language. Where the
+/// name has been transliterated from another script the romanization system
+/// used is also included.
+///
+#[cfg(feature = "languages")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocalizedName {
+    language: LanguageCode,
+    name: &'static str,
+    local_variation: Option<&'static str>,
+    romanization_system: Option<&'static str>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "languages")]
+impl Display for LocalizedName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(feature = "languages")]
+impl LocalizedName {
+    /// Returns the language this name is written in.
+    pub const fn language(&self) -> LanguageCode {
+        self.language
+    }
+
+    /// Returns the name itself.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns any local variation of the name.
+    pub const fn local_variation(&self) -> Option<&'static str> {
+        self.local_variation
+    }
+
+    /// Returns the system used to romanize this name, if any; for example
+    /// `"BGN/PCGN 1947"`.
+    pub const fn romanization_system(&self) -> Option<&'static str> {
+        self.romanization_system
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -110,3 +196,43 @@ pub mod part_1;
 
 #[cfg(feature = "part_2")]
 pub mod part_2;
+
+#[cfg(feature = "part_3")]
+pub mod part_3;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "languages"))]
+mod tests {
+    use super::*;
+    use crate::part_1::CountryCode;
+
+    #[test]
+    fn test_country_names_in() {
+        let names = CountryCode::BY.short_name_in(LanguageCode::Fra);
+        assert_eq!(names.len(), 1);
+        assert_eq!(names[0].name(), "Bélarus (le)");
+        assert_eq!(names[0].language(), LanguageCode::Fra);
+        assert_eq!(names[0].romanization_system(), None);
+
+        let names = CountryCode::BY.full_name_in(LanguageCode::Eng);
+        assert_eq!(names[0].to_string(), "the Republic of Belarus");
+
+        assert!(CountryCode::BY.short_name_in(LanguageCode::Deu).is_empty());
+    }
+
+    #[cfg(feature = "part_2")]
+    #[test]
+    fn test_subdivision_names_in() {
+        use crate::part_2::SubdivisionCode;
+
+        let names = SubdivisionCode::BY_BR.subdivision_name_in(LanguageCode::Rus);
+        assert_eq!(names.len(), 2);
+        assert!(names.iter().any(|name| name.name() == "Brestskaya oblast'"
+            && name.romanization_system() == Some("BGN/PCGN 1947")));
+        assert!(names.iter().any(|name| name.name() == "Brestskaja oblast'"
+            && name.romanization_system() == Some("GOST 1983 = UN V/18 1987")));
+    }
+}
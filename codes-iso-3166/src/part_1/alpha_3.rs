@@ -0,0 +1,95 @@
+/*!
+Provides functions for use with the `#[serde(with = "...")]` attribute to
+represent a [CountryCode] by its alpha-3 code rather than its alpha-2 code.
+
+# Example
+
+```rust
+use codes_iso_3166::part_1::CountryCode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Address {
+    #[serde(with = "codes_iso_3166::part_1::alpha_3")]
+    country: CountryCode,
+}
+
+let address = Address { country: CountryCode::DE };
+let json = serde_json::to_string(&address).unwrap();
+assert_eq!(json, r#"{"country":"DEU"}"#);
+assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+```
+
+*/
+
+use super::{indices, CountryCode};
+use serde::de::{Error as _, Unexpected};
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serialize `code` as its alpha-3 code, this is an error for codes without
+/// an alpha-3 code.
+///
+pub fn serialize<S>(code: &CountryCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match code.alpha_3_code() {
+        Some(alpha_3_code) => serializer.serialize_str(alpha_3_code),
+        None => Err(S::Error::custom(format!(
+            "country code {code} has no alpha-3 code"
+        ))),
+    }
+}
+
+///
+/// Deserialize a [CountryCode] from its alpha-3 code, where an officially
+/// assigned and a reserved code share an alpha-3 code the assigned code is
+/// returned.
+///
+pub fn deserialize<'de, D>(deserializer: D) -> Result<CountryCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    indices::find_by_alpha_3_code(&s)
+        .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&s), &"an ISO 3166-1 alpha-3 code"))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part_1::ALL_CODES;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper(#[serde(with = "super")] CountryCode);
+
+    #[test]
+    fn test_alpha_3_round_trip() {
+        for code in ALL_CODES
+            .iter()
+            .filter(|code| code.alpha_3_code().is_some())
+        {
+            let json = serde_json::to_string(&Wrapper(*code)).unwrap();
+            assert_eq!(json, format!("\"{}\"", code.alpha_3_code().unwrap()));
+            let read = serde_json::from_str::<Wrapper>(&json).unwrap();
+            assert_eq!(serde_json::to_string(&read).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_alpha_3_invalid() {
+        assert!(serde_json::from_str::<Wrapper>(r#""US""#).is_err());
+        assert!(serde_json::from_str::<Wrapper>(r#""XYZ""#).is_err());
+    }
+}
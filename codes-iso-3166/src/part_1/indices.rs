@@ -1,8 +1,16 @@
 /*!
 Provides lookup functions for [CountryCode] by secondary identifiers.
-*/
 
-use crate::part_1::CountryCode;
+All indices are perfect hash maps generated at build time and so lookups are
+constant-time and do not allocate.
+
+```rust
+use codes_iso_3166::part_1::{indices, CountryCode};
+
+assert_eq!(indices::find_by_short_name("France"), Some(CountryCode::FR));
+assert_eq!(indices::find_by_short_name("CÔTE D'IVOIRE"), Some(CountryCode::CI));
+```
+*/
 
 // ------------------------------------------------------------------------------------------------
 // Public Macros
@@ -21,15 +29,7 @@ use crate::part_1::CountryCode;
 ///
 #[cfg(feature = "alpha_3_code")]
 pub fn find_by_alpha_3_code(code: &str) -> Option<CountryCode> {
-    // TODO: use lazy_static and HashMaps for actual indices!
-    if code.len() == 3 && code.chars().all(|c| c.is_ascii_alphanumeric()) {
-        crate::part_1::ALL_CODES
-            .iter()
-            .find(|c| c.alpha_3_code().map(|c| c == code).unwrap_or_default())
-            .cloned()
-    } else {
-        None
-    }
+    ALPHA_3_CODE_INDEX.get(code).cloned()
 }
 
 ///
@@ -37,25 +37,79 @@ pub fn find_by_alpha_3_code(code: &str) -> Option<CountryCode> {
 ///
 #[cfg(feature = "numeric_code")]
 pub fn find_by_numeric_code(code: u16) -> Option<CountryCode> {
-    // TODO: use lazy_static and HashMaps for actual indices!
-    crate::part_1::ALL_CODES
-        .iter()
-        .find(|c| c.numeric_code().map(|c| c == code).unwrap_or_default())
-        .cloned()
+    NUMERIC_CODE_INDEX.get(&code).cloned()
 }
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+///
+/// Lookup a [CountryCode] by the three-letter code of the country that
+/// formerly used the same two-letter code.
+///
+#[cfg(feature = "formerly")]
+pub fn find_by_former_alpha_3_code(code: &str) -> Option<CountryCode> {
+    FORMER_ALPHA_3_CODE_INDEX.get(code).cloned()
+}
 
-// ------------------------------------------------------------------------------------------------
-// Implementations
-// ------------------------------------------------------------------------------------------------
+///
+/// Lookup a [CountryCode] by it's short name, the comparison is
+/// case-insensitive and so the names are indexed in upper case.
+///
+pub fn find_by_short_name(name: &str) -> Option<CountryCode> {
+    SHORT_NAME_INDEX.get(&UniCase::new(name)).cloned()
+}
 
 // ------------------------------------------------------------------------------------------------
-// Private Functions
+//
+// The rest of this file is generated by the package build script.
+//
 // ------------------------------------------------------------------------------------------------
 
+include!(concat!(env!("OUT_DIR"), "/part_1_indices.rs"));
+
 // ------------------------------------------------------------------------------------------------
-// Modules
+// Unit Tests
 // ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alpha_3_code")]
+    #[test]
+    fn test_find_by_alpha_3_code() {
+        assert_eq!(find_by_alpha_3_code("USA"), Some(CountryCode::US));
+        assert_eq!(find_by_alpha_3_code("usa"), None);
+        assert_eq!(find_by_alpha_3_code("XXX"), None);
+    }
+
+    #[cfg(feature = "numeric_code")]
+    #[test]
+    fn test_find_by_numeric_code() {
+        assert_eq!(find_by_numeric_code(840), Some(CountryCode::US));
+        assert_eq!(find_by_numeric_code(28), Some(CountryCode::AG));
+        assert_eq!(find_by_numeric_code(999), None);
+        // BU (Burma) is transitionally reserved, and shares MM's numeric code.
+        assert_eq!(find_by_numeric_code(104), Some(CountryCode::MM));
+    }
+
+    #[cfg(feature = "formerly")]
+    #[test]
+    fn test_find_by_former_alpha_3_code() {
+        assert_eq!(find_by_former_alpha_3_code("SKM"), Some(CountryCode::SK));
+        assert_eq!(find_by_former_alpha_3_code("SVK"), None);
+    }
+
+    #[test]
+    fn test_find_by_short_name() {
+        assert_eq!(
+            find_by_short_name("Antigua and Barbuda"),
+            Some(CountryCode::AG)
+        );
+        assert_eq!(
+            find_by_short_name("ANTIGUA AND BARBUDA"),
+            Some(CountryCode::AG)
+        );
+        assert_eq!(find_by_short_name("Atlantis"), None);
+        // DY (Dahomey) is transitionally reserved, and shares BJ's short name.
+        assert_eq!(find_by_short_name("Benin"), Some(CountryCode::BJ));
+    }
+}
@@ -99,3 +99,34 @@ pub use status::Status;
 
 #[cfg(feature = "indices")]
 pub mod indices;
+
+#[cfg(all(feature = "serde", feature = "alpha_3_code", feature = "indices"))]
+pub mod alpha_3;
+
+#[cfg(all(feature = "serde", feature = "numeric_code", feature = "indices"))]
+pub mod numeric;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_round_trip() {
+        for code in ALL_CODES {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(json, format!("\"{code}\""));
+            assert_eq!(serde_json::from_str::<CountryCode>(&json).unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn test_defining_standard() {
+        assert_eq!(ISO_3166_1.edition(), Some("2020"));
+        assert_eq!(ISO_3166_1.publication_date(), Some("2020-08"));
+        assert_eq!(ISO_3166_1.registration_authority(), Some(Agency::ISO));
+    }
+}
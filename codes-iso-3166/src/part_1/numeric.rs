@@ -0,0 +1,115 @@
+/*!
+Provides functions for use with the `#[serde(with = "...")]` attribute to
+represent a [CountryCode] by its numeric code rather than its alpha-2 code.
+
+Codes are serialized as integers, but may be deserialized from either an
+integer or a string of digits such as `"036"`.
+
+# Example
+
+```rust
+use codes_iso_3166::part_1::CountryCode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Address {
+    #[serde(with = "codes_iso_3166::part_1::numeric")]
+    country: CountryCode,
+}
+
+let address = Address { country: CountryCode::AU };
+let json = serde_json::to_string(&address).unwrap();
+assert_eq!(json, r#"{"country":36}"#);
+assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+assert_eq!(
+    serde_json::from_str::<Address>(r#"{"country":"036"}"#).unwrap(),
+    address
+);
+```
+
+*/
+
+use super::{indices, CountryCode};
+use codes_common::serde_helpers::deserialize_numeric_code;
+use serde::de::{Error as _, Unexpected};
+use serde::ser::Error as _;
+use serde::{Deserializer, Serializer};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serialize `code` as its numeric code, this is an error for codes without
+/// a numeric code.
+///
+pub fn serialize<S>(code: &CountryCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match code.numeric_code() {
+        Some(numeric_code) => serializer.serialize_u16(numeric_code),
+        None => Err(S::Error::custom(format!(
+            "country code {code} has no numeric code"
+        ))),
+    }
+}
+
+///
+/// Deserialize a [CountryCode] from its numeric code, where an officially
+/// assigned and a reserved code share a numeric code the assigned code is
+/// returned.
+///
+pub fn deserialize<'de, D>(deserializer: D) -> Result<CountryCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let numeric_code = deserialize_numeric_code(deserializer)?;
+    indices::find_by_numeric_code(numeric_code).ok_or_else(|| {
+        D::Error::invalid_value(
+            Unexpected::Unsigned(numeric_code.into()),
+            &"an ISO 3166-1 numeric code",
+        )
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part_1::ALL_CODES;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper(#[serde(with = "super")] CountryCode);
+
+    #[test]
+    fn test_numeric_round_trip() {
+        for code in ALL_CODES
+            .iter()
+            .filter(|code| code.numeric_code().is_some())
+        {
+            let json = serde_json::to_string(&Wrapper(*code)).unwrap();
+            assert_eq!(json, code.numeric_code().unwrap().to_string());
+            let read = serde_json::from_str::<Wrapper>(&json).unwrap();
+            assert_eq!(serde_json::to_string(&read).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_numeric_from_string() {
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""004""#).unwrap().0,
+            CountryCode::AF
+        );
+        assert_eq!(
+            serde_json::from_str::<Wrapper>("104").unwrap().0,
+            CountryCode::MM
+        );
+        assert!(serde_json::from_str::<Wrapper>(r#""AF""#).is_err());
+        assert!(serde_json::from_str::<Wrapper>("999").is_err());
+    }
+}
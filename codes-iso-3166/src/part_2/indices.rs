@@ -1,8 +1,22 @@
 /*!
 Provides lookup functions for [super::SubdivisionCode] by secondary identifiers.
+
+All indices are perfect hash maps generated at build time and so lookups are
+constant-time and do not allocate.
+
+```rust
+use codes_iso_3166::part_1::CountryCode;
+use codes_iso_3166::part_2::{indices, SubdivisionCode};
+
+assert_eq!(indices::find_by_code("US-CA"), Some(SubdivisionCode::US_CA));
+assert_eq!(indices::subdivisions_of_country(CountryCode::US).len(), 57);
+```
 */
 
-// use ...
+use crate::part_1::CountryCode;
+
+#[cfg(feature = "categories")]
+use crate::part_2::categories::SubdivisionCategoryCode;
 
 // ------------------------------------------------------------------------------------------------
 // Public Macros
@@ -16,18 +30,95 @@ Provides lookup functions for [super::SubdivisionCode] by secondary identifiers.
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+///
+/// Lookup a [SubdivisionCode] by it's standard, hyphenated, form such as
+/// `"US-CA"`.
+///
+pub fn find_by_code(code: &str) -> Option<SubdivisionCode> {
+    CODE_INDEX.get(code).cloned()
+}
 
-// ------------------------------------------------------------------------------------------------
-// Implementations
-// ------------------------------------------------------------------------------------------------
+///
+/// Return all the subdivisions of the provided country, this will be an
+/// empty slice for countries with no subdivisions.
+///
+pub fn subdivisions_of_country(country: CountryCode) -> &'static [SubdivisionCode] {
+    COUNTRY_INDEX
+        .get(country.alpha_2_code())
+        .cloned()
+        .unwrap_or_default()
+}
+
+///
+/// Return the subdivisions that have `parent` as their
+/// `parent_subdivision_code`.
+///
+pub fn children_of(parent: SubdivisionCode) -> &'static [SubdivisionCode] {
+    PARENT_INDEX.get(parent.code()).cloned().unwrap_or_default()
+}
+
+///
+/// Return all the subdivisions with the provided category.
+///
+#[cfg(feature = "categories")]
+pub fn subdivisions_in_category(category: SubdivisionCategoryCode) -> &'static [SubdivisionCode] {
+    CATEGORY_INDEX
+        .get(&category.code())
+        .cloned()
+        .unwrap_or_default()
+}
 
 // ------------------------------------------------------------------------------------------------
-// Private Functions
+//
+// The rest of this file is generated by the package build script.
+//
 // ------------------------------------------------------------------------------------------------
 
+include!(concat!(env!("OUT_DIR"), "/part_2_indices.rs"));
+
 // ------------------------------------------------------------------------------------------------
-// Modules
+// Unit Tests
 // ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_by_code() {
+        assert_eq!(find_by_code("US-CA"), Some(SubdivisionCode::US_CA));
+        assert_eq!(find_by_code("US_CA"), None);
+        assert_eq!(find_by_code("US-XX"), None);
+    }
+
+    #[test]
+    fn test_subdivisions_of_country() {
+        let subdivisions = subdivisions_of_country(CountryCode::US);
+        assert!(subdivisions.contains(&SubdivisionCode::US_CA));
+        assert!(subdivisions
+            .iter()
+            .all(|code| code.country_code() == CountryCode::US));
+        assert!(subdivisions_of_country(CountryCode::AQ).is_empty());
+    }
+
+    #[test]
+    fn test_children_of() {
+        let children = children_of(SubdivisionCode::GB_ENG);
+        assert!(!children.is_empty());
+        assert!(children
+            .iter()
+            .all(|code| code.parent_subdivision_code() == Some(SubdivisionCode::GB_ENG)));
+        assert!(children_of(SubdivisionCode::US_CA).is_empty());
+    }
+
+    #[cfg(feature = "categories")]
+    #[test]
+    fn test_subdivisions_in_category() {
+        let category = SubdivisionCode::US_CA.category_code();
+        let subdivisions = subdivisions_in_category(category);
+        assert!(subdivisions.contains(&SubdivisionCode::US_TX));
+        assert!(subdivisions
+            .iter()
+            .all(|code| code.category_code() == category));
+    }
+}
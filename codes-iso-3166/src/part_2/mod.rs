@@ -61,3 +61,72 @@ pub mod territories;
 
 #[cfg(feature = "indices")]
 pub mod indices;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_code_string() {
+        assert_eq!(
+            serde_json::to_string(&SubdivisionCode::US_CA).unwrap(),
+            r#""US-CA""#
+        );
+        assert_eq!(
+            serde_json::from_str::<SubdivisionCode>(r#""US-CA""#).unwrap(),
+            SubdivisionCode::US_CA
+        );
+        // the variant name is still accepted.
+        assert_eq!(
+            serde_json::from_str::<SubdivisionCode>(r#""US_CA""#).unwrap(),
+            SubdivisionCode::US_CA
+        );
+        assert!(serde_json::from_str::<SubdivisionCode>(r#""US-XX""#).is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        for code in ALL_CODES {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(json, format!("\"{code}\""));
+            assert_eq!(
+                serde_json::from_str::<SubdivisionCode>(&json).unwrap(),
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde_collections() {
+        use codes_common::collections::{CodeMap, CodeSet};
+        use codes_common::{code_map, code_set};
+
+        let set: CodeSet<SubdivisionCode> = code_set![
+            SubdivisionCode::US_CA,
+            SubdivisionCode::US_WA,
+            SubdivisionCode::GB_ENG,
+        ];
+        let json = serde_json::to_string(&set).unwrap();
+        assert!(json.contains(r#""US-CA""#));
+        assert!(json.contains(r#""GB-ENG""#));
+        assert_eq!(
+            serde_json::from_str::<CodeSet<SubdivisionCode>>(&json).unwrap(),
+            set
+        );
+
+        let map: CodeMap<SubdivisionCode, u32> = code_map! {
+            SubdivisionCode::US_CA => 1850,
+            SubdivisionCode::US_WA => 1889,
+        };
+        let json = serde_json::to_string(&map).unwrap();
+        assert!(json.contains(r#""US-CA":1850"#));
+        let read = serde_json::from_str::<CodeMap<SubdivisionCode, u32>>(&json).unwrap();
+        assert_eq!(read.get(&SubdivisionCode::US_CA), Some(&1850));
+        assert_eq!(read.get(&SubdivisionCode::US_WA), Some(&1889));
+        assert_eq!(read.len(), 2);
+    }
+}
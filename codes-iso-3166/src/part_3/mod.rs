@@ -0,0 +1,235 @@
+/*!
+Codes for the representation of names of countries and their subdivisions –
+Part 3: Code for formerly used names of countries, defines codes for country
+names which have been deleted from ISO 3166-1 since its first publication in
+1974.
+
+Each former country name is represented by a four-letter code; the first two
+letters are the ISO 3166-1 alpha-2 code of the former country and the second
+two letters depend on how the country was replaced:
+
+* where the country was divided into parts, the alpha-2 code of the country
+  is followed by `HH`, for example `CSHH` (Czechoslovakia);
+* where the country merged with, or changed its name to, another country, the
+  alpha-2 code of the new country, for example `BUMM` (Burma, now Myanmar);
+* where the new alpha-2 code is also the old one, another pair of letters is
+  used such as `AA` or `XX`, for example `BYAA` (Byelorussian SSR).
+
+# Example
+
+```rust
+use codes_iso_3166::part_1::CountryCode;
+use codes_iso_3166::part_3::{self, FormerCountryCode};
+
+assert_eq!(
+    FormerCountryCode::CSHH.successors(),
+    &[CountryCode::CZ, CountryCode::SK]
+);
+assert_eq!(FormerCountryCode::CSHH.valid_until(), 1993);
+
+assert_eq!(part_3::successors_of("SUHH").len(), 13);
+assert_eq!(part_3::successors_of_withdrawn("SU", Some(1992)).len(), 13);
+assert_eq!(part_3::successors_of_withdrawn("SUN", None).len(), 13);
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Find all the former countries identified by the provided code, which may
+/// be the four-letter ISO 3166-3 code or the withdrawn alpha-2 or alpha-3
+/// code. Note that some alpha-2 codes have been assigned, and withdrawn, more
+/// than once; for example `CS` identifies both Czechoslovakia and Serbia and
+/// Montenegro.
+///
+/// Some withdrawn alpha-2 codes have since been reassigned to current
+/// countries in ISO 3166-1, so clients should only resolve codes here that
+/// are not found in [crate::part_1::CountryCode].
+///
+pub fn find_by_former_code(code: &str) -> impl Iterator<Item = FormerCountryCode> + '_ {
+    ALL_CODES.into_iter().filter(move |former| {
+        former.code() == code || former.alpha_2_code() == code || former.alpha_3_code() == code
+    })
+}
+
+///
+/// Find all the former countries identified by the provided numeric code.
+/// Numeric codes may be shared, for example `891` identifies both Yugoslavia
+/// and Serbia and Montenegro, and may also have been reassigned to a current
+/// country in ISO 3166-1.
+///
+pub fn find_by_former_numeric_code(numeric_code: u16) -> impl Iterator<Item = FormerCountryCode> {
+    ALL_CODES
+        .into_iter()
+        .filter(move |former| former.numeric_code() == Some(numeric_code))
+}
+
+///
+/// Resolve a four-letter ISO 3166-3 code to the currently assigned codes of
+/// the countries that succeeded it. The result is empty if the code is not
+/// known.
+///
+/// The withdrawn alpha-2 and alpha-3 codes are not accepted here as some have
+/// since been reassigned, for example `SK` identified Sikkim before it
+/// identified Slovakia; use [successors_of_withdrawn] to resolve those.
+///
+pub fn successors_of(code: &str) -> Vec<CountryCode> {
+    FormerCountryCode::from_str(code)
+        .map(|former| former.successors().to_vec())
+        .unwrap_or_default()
+}
+
+///
+/// Resolve a withdrawn alpha-2 or alpha-3 code, together with the year in
+/// which it was withdrawn, to the currently assigned codes of the countries
+/// that succeeded it. The year may be `None` where the code was only
+/// withdrawn once, such as `DDR`, but is needed for codes such as `CS` which
+/// was withdrawn in both 1993 and 2006. The result is empty if no former
+/// country matches both the code and the year, or if no year is provided and
+/// the code is ambiguous.
+///
+pub fn successors_of_withdrawn(code: &str, valid_until: Option<u16>) -> Vec<CountryCode> {
+    let formers: Vec<FormerCountryCode> = ALL_CODES
+        .into_iter()
+        .filter(|former| {
+            (valid_until.is_none() || valid_until == Some(former.valid_until()))
+                && (former.alpha_2_code() == code || former.alpha_3_code() == code)
+        })
+        .collect();
+    if formers
+        .iter()
+        .any(|former| former.valid_until() != formers[0].valid_until())
+    {
+        return Default::default();
+    }
+
+    let mut successors: Vec<CountryCode> = Default::default();
+    for former in formers {
+        for successor in former.successors() {
+            if !successors.contains(successor) {
+                successors.push(*successor);
+            }
+        }
+    }
+    successors
+}
+
+// ------------------------------------------------------------------------------------------------
+//
+// The rest of this file is generated by the package build script.
+//
+// ------------------------------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/part_3.rs"));
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_former_country_code() {
+        let former = FormerCountryCode::from_str("YUCS").unwrap();
+        assert_eq!(former, FormerCountryCode::YUCS);
+        assert_eq!(former.to_string(), "YUCS");
+        assert_eq!(former.name(), "Yugoslavia");
+        assert_eq!(former.alpha_2_code(), "YU");
+        assert_eq!(former.alpha_3_code(), "YUG");
+        assert_eq!(former.numeric_code(), Some(891));
+        assert_eq!(former.valid_from(), 1974);
+        assert_eq!(former.valid_until(), 2003);
+        assert!(FormerCountryCode::from_str("YU").is_err());
+    }
+
+    #[test]
+    fn test_find_by_former_code() {
+        assert_eq!(
+            find_by_former_code("CS").collect::<Vec<_>>(),
+            vec![FormerCountryCode::CSHH, FormerCountryCode::CSXX]
+        );
+        assert_eq!(
+            find_by_former_code("CSK").collect::<Vec<_>>(),
+            vec![FormerCountryCode::CSHH]
+        );
+        assert_eq!(find_by_former_code("XX").count(), 0);
+    }
+
+    #[test]
+    fn test_find_by_former_numeric_code() {
+        assert_eq!(
+            find_by_former_numeric_code(698).collect::<Vec<_>>(),
+            vec![FormerCountryCode::SKIN]
+        );
+        assert_eq!(FormerCountryCode::BQAQ.numeric_code(), Some(80));
+        assert_eq!(FormerCountryCode::PZPA.numeric_code(), Some(594));
+        assert_eq!(
+            find_by_former_numeric_code(891).collect::<Vec<_>>(),
+            vec![FormerCountryCode::CSXX, FormerCountryCode::YUCS]
+        );
+        assert_eq!(find_by_former_numeric_code(999).count(), 0);
+    }
+
+    #[test]
+    fn test_successors_of() {
+        assert_eq!(successors_of("DDDE"), vec![CountryCode::DE]);
+        assert_eq!(
+            successors_of("CSHH"),
+            vec![CountryCode::CZ, CountryCode::SK]
+        );
+        assert!(successors_of("SUHH").contains(&CountryCode::RU));
+        assert!(successors_of("DDR").is_empty());
+        assert!(successors_of("USA").is_empty());
+    }
+
+    #[test]
+    fn test_successors_of_reassigned_code() {
+        assert!(successors_of("SK").is_empty());
+        assert_eq!(successors_of("SKIN"), vec![CountryCode::IN]);
+        assert!(successors_of("BY").is_empty());
+        assert_eq!(successors_of("BYAA"), vec![CountryCode::BY]);
+    }
+
+    #[test]
+    fn test_successors_of_withdrawn() {
+        assert_eq!(
+            successors_of_withdrawn("DDR", Some(1990)),
+            vec![CountryCode::DE]
+        );
+        assert_eq!(
+            successors_of_withdrawn("SK", Some(1975)),
+            vec![CountryCode::IN]
+        );
+        assert_eq!(
+            successors_of_withdrawn("BY", Some(1992)),
+            vec![CountryCode::BY]
+        );
+        assert_eq!(
+            successors_of_withdrawn("CS", Some(1993)),
+            vec![CountryCode::CZ, CountryCode::SK]
+        );
+        assert_eq!(
+            successors_of_withdrawn("CS", Some(2006)),
+            vec![CountryCode::ME, CountryCode::RS]
+        );
+        assert!(successors_of_withdrawn("SK", Some(1993)).is_empty());
+    }
+
+    #[test]
+    fn test_successors_of_withdrawn_without_year() {
+        assert_eq!(successors_of_withdrawn("DDR", None), vec![CountryCode::DE]);
+        assert_eq!(successors_of_withdrawn("DD", None), vec![CountryCode::DE]);
+        assert_eq!(
+            successors_of_withdrawn("CSK", None),
+            vec![CountryCode::CZ, CountryCode::SK]
+        );
+        // withdrawn for both Czechoslovakia and Serbia and Montenegro.
+        assert!(successors_of_withdrawn("CS", None).is_empty());
+        assert!(successors_of_withdrawn("XX", None).is_empty());
+    }
+}
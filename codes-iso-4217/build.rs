@@ -4,9 +4,12 @@ use codes_common::build::{
 use quick_xml::events::Event;
 use quick_xml::name::QName;
 use quick_xml::reader::Reader;
-use std::error::Error;
+use std::{collections::BTreeMap, error::Error, fs::File, str::FromStr};
 use tera::{Context, Map, Number, Value};
 
+#[cfg(feature = "countries")]
+use codes_iso_3166::part_1::{indices::find_by_short_name, CountryCode};
+
 #[derive(Debug)]
 enum XmlState {
     Top,
@@ -25,10 +28,38 @@ struct Data {
     codes: Map<String, Value>,
     active_file_date: String,
     historical_file_date: String,
+    // alpha code -> (entity name, is fund) for every active entry.
+    entities: BTreeMap<String, Vec<(String, bool)>>,
+    // country alpha-2 code -> (legal tender alpha codes, fund alpha codes).
+    country_currencies: BTreeMap<String, (Vec<String>, Vec<String>)>,
 }
 
 const TYPE_NAME: &str = "CurrencyCode";
 
+// Entity names in list-one that do not match a country's short name in ISO 3166-1.
+#[cfg(feature = "countries")]
+const ENTITY_NAME_ALIASES: &[(&str, CountryCode)] = &[
+    (
+        "KOREA (THE DEMOCRATIC PEOPLE’S REPUBLIC OF)",
+        CountryCode::KP,
+    ),
+    ("LAO PEOPLE’S DEMOCRATIC REPUBLIC (THE)", CountryCode::LA),
+    ("SYRIAN ARAB REPUBLIC", CountryCode::SY),
+    ("TANZANIA, UNITED REPUBLIC OF", CountryCode::TZ),
+    ("TÜRKİYE", CountryCode::TR),
+    ("WESTERN SAHARA", CountryCode::EH),
+];
+
+// Entity names in list-one that are not countries, this excludes the `ZZnn_`
+// entries for bond market units, precious metals, and testing codes.
+#[cfg(feature = "countries")]
+const NON_COUNTRY_ENTITY_NAMES: &[&str] = &[
+    "EUROPEAN UNION",
+    "INTERNATIONAL MONETARY FUND (IMF)",
+    "MEMBER COUNTRIES OF THE AFRICAN DEVELOPMENT BANK GROUP",
+    "SISTEMA UNITARIO DE COMPENSACION REGIONAL DE PAGOS \"SUCRE\"",
+];
+
 fn main() -> Result<(), Box<dyn Error>> {
     process(
         default_init,
@@ -36,6 +67,8 @@ fn main() -> Result<(), Box<dyn Error>> {
             process_iso_list_xml(ctx, "list-one.xml", false)
                 .and_then(|ctx| process_iso_list_xml(ctx, "list-three.xml", true))
                 .and_then(process_symbol_data)
+                .and_then(process_country_data)
+                .and_then(process_successor_data)
         },
         default_finalize,
         make_default_renderer("lib._rs", "generated.rs"),
@@ -50,7 +83,7 @@ fn process_iso_list_xml(
     let file_name = input_file_name(file_name);
 
     let mut reader = Reader::from_file(file_name)?;
-    reader.trim_text(true);
+    reader.config_mut().trim_text(true);
 
     let is_historical_default = Value::Bool(is_historical);
     let is_fund_default = Value::Bool(false);
@@ -115,28 +148,49 @@ fn process_iso_list_xml(
                             let number: Number = text.parse::<u16>()?.into();
                             entry.insert("numeric_code".to_string(), Value::Number(number));
                         }
-                        XmlState::Units => {
-                            if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) {
-                                let number: Number = text.parse::<u16>()?.into();
-                                entry.insert("monetary_units".to_string(), Value::Number(number));
-                            }
+                        XmlState::Units
+                            if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) =>
+                        {
+                            let number: Number = text.parse::<u16>()?.into();
+                            entry.insert("monetary_units".to_string(), Value::Number(number));
                         }
                         XmlState::WithdrawalDate => {
-                            entry.insert("withdrawal_date".to_string(), Value::String(text));
+                            let (year, month) = parse_withdrawal_date(&text)?;
+                            entry.insert("withdrawal_year".to_string(), year.into());
+                            entry.insert("withdrawal_month".to_string(), month.into());
                         }
                         _ => (),
                     };
                 }
-                Ok(Event::End(e)) => {
+                Ok(Event::End(e))
                     if e.name() == XmlState::Entry.tag_name()
-                        || e.name() == XmlState::Historical.tag_name()
-                    {
-                        if !alpha_code.is_empty() {
+                        || e.name() == XmlState::Historical.tag_name() =>
+                {
+                    if !alpha_code.is_empty() {
+                        if !is_historical {
+                            if let Some(Value::String(name)) = entry.get("country_name") {
+                                let is_fund = entry.get("is_fund") == Some(&Value::Bool(true));
+                                data.entities
+                                    .entry(alpha_code.clone())
+                                    .or_default()
+                                    .push((name.replace("\\\"", "\""), is_fund));
+                            }
+                        }
+                        // Codes withdrawn from one country may still be active for
+                        // others, such as EUR, and so must not replace active entries.
+                        // Codes withdrawn for more than one country keep the latest date.
+                        let replace = match data.codes.get(&alpha_code) {
+                            Some(existing) => {
+                                !is_historical || is_later_withdrawal(&entry, existing)
+                            }
+                            None => true,
+                        };
+                        if replace {
                             data.codes
                                 .insert(alpha_code.clone(), Value::Object(entry.clone()));
                         }
-                        break;
                     }
+                    break;
                 }
                 Ok(Event::Eof) => {
                     return Ok(data);
@@ -161,7 +215,7 @@ fn process_iso_list_xml(
 // ------------------------------------------------------------------------------------------------
 
 impl XmlState {
-    pub fn tag_name(&self) -> QName {
+    pub fn tag_name(&self) -> QName<'_> {
         QName(match self {
             Self::Top => b"ISO_4217",
             Self::Entry => b"CcyNtry",
@@ -218,10 +272,161 @@ impl From<Data> for Context {
 
         ctx.insert("codes", &Value::Object(data.codes));
 
+        ctx.insert(
+            "country_currencies",
+            &Value::Object(
+                data.country_currencies
+                    .into_iter()
+                    .map(|(country, (legal_tender, funds))| {
+                        let mut currencies = Map::new();
+                        currencies.insert("legal_tender".to_string(), legal_tender.into());
+                        currencies.insert("funds".to_string(), funds.into());
+                        (country, Value::Object(currencies))
+                    })
+                    .collect(),
+            ),
+        );
+
         ctx
     }
 }
 
+fn process_successor_data(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    let file_name = input_file_name("successors.csv");
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(File::open(file_name)?);
+
+    for result in rdr.records() {
+        let record = result?;
+
+        let alpha_code = record.get(0).unwrap();
+        let successor = record.get(1).unwrap();
+        if !data.codes.contains_key(successor) {
+            return Err(format!("Unknown successor {} for {}", successor, alpha_code).into());
+        }
+
+        let date: Vec<u16> = record
+            .get(2)
+            .unwrap()
+            .split('-')
+            .map(u16::from_str)
+            .collect::<Result<Vec<u16>, _>>()?;
+        assert_eq!(date.len(), 3, "expecting conversion date as YYYY-MM-DD");
+
+        let row = data
+            .codes
+            .get_mut(alpha_code)
+            .ok_or_else(|| format!("Unknown historical code {}", alpha_code))?
+            .as_object_mut()
+            .unwrap();
+        row.insert("successor".to_string(), successor.into());
+        row.insert("conversion_year".to_string(), date[0].into());
+        row.insert("conversion_month".to_string(), date[1].into());
+        row.insert("conversion_day".to_string(), date[2].into());
+
+        // A successor is introduced at the earliest conversion to it.
+        let row = data
+            .codes
+            .get_mut(successor)
+            .unwrap()
+            .as_object_mut()
+            .unwrap();
+        let introduced = (
+            row.get("introduction_year").and_then(Value::as_u64),
+            row.get("introduction_month").and_then(Value::as_u64),
+            row.get("introduction_day").and_then(Value::as_u64),
+        );
+        let converted = (
+            Some(date[0] as u64),
+            Some(date[1] as u64),
+            Some(date[2] as u64),
+        );
+        if introduced.0.is_none() || converted < introduced {
+            row.insert("introduction_year".to_string(), date[0].into());
+            row.insert("introduction_month".to_string(), date[1].into());
+            row.insert("introduction_day".to_string(), date[2].into());
+        }
+    }
+
+    Ok(data)
+}
+
+// Withdrawal dates are either `YYYY-MM`, `YYYY`, or a range such as
+// `YYYY to YYYY`; the start of the period is used, defaulting to January.
+fn parse_withdrawal_date(s: &str) -> Result<(u16, u16), Box<dyn Error>> {
+    let start = s.split(" to ").next().unwrap().trim();
+    match start.split_once('-') {
+        Some((year, month)) => Ok((u16::from_str(year)?, u16::from_str(month)?)),
+        None => Ok((u16::from_str(start)?, 1)),
+    }
+}
+
+fn is_later_withdrawal(entry: &Map<String, Value>, existing: &Value) -> bool {
+    let date_of = |row: &Map<String, Value>| {
+        (
+            row.get("withdrawal_year").and_then(Value::as_u64),
+            row.get("withdrawal_month").and_then(Value::as_u64),
+        )
+    };
+    match existing.as_object() {
+        Some(existing) if existing.get("is_historical") == Some(&Value::Bool(true)) => {
+            date_of(entry) > date_of(existing)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(feature = "countries")]
+fn process_country_data(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    for (alpha_code, entities) in &data.entities {
+        let mut countries: Vec<Value> = Default::default();
+        for (name, is_fund) in entities {
+            let name = name.trim();
+            let country = ENTITY_NAME_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map(|(_, country)| *country)
+                .or_else(|| find_by_short_name(name));
+            if let Some(country) = country {
+                let country = country.alpha_2_code().to_string();
+                if !countries.contains(&Value::String(country.clone())) {
+                    countries.push(country.clone().into());
+                }
+                let (legal_tender, funds) = data.country_currencies.entry(country).or_default();
+                if *is_fund {
+                    funds.push(alpha_code.clone());
+                } else {
+                    legal_tender.push(alpha_code.clone());
+                }
+            } else if !(name.starts_with("ZZ") || NON_COUNTRY_ENTITY_NAMES.contains(&name)) {
+                println!(
+                    "cargo:warning=Could not resolve entity name {:?} for currency {} to a country code",
+                    name, alpha_code
+                );
+            }
+        }
+        let row = data
+            .codes
+            .get_mut(alpha_code)
+            .unwrap()
+            .as_object_mut()
+            .unwrap();
+        row.insert("countries".to_string(), Value::Array(countries));
+    }
+
+    Ok(data)
+}
+
+// Without the `countries` feature no mapping is generated, and so the ISO 3166
+// package is not required at build time.
+#[cfg(not(feature = "countries"))]
+fn process_country_data(data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    Ok(data)
+}
+
 fn process_symbol_data(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
     use scraper::{Html, Selector};
     use std::fs;
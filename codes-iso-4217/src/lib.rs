@@ -39,6 +39,7 @@ assert_eq!(code.numeric_code(), Some(84));
 // feature = "historical_codes"
 // assert_eq!(code.is_historical(), false);
 // assert_eq!(code.withdrawal_date(), None);
+// assert_eq!(code.successor(), None);
 
 // feature = "symbols"
 // assert_eq!(code.currency_symbol_str(), Some("BZ$"));
@@ -52,13 +53,19 @@ assert_eq!(ISO_4217.title(), "Currency codes");
 By default only the `serde` feature is enabled, the [CurrencyCode::alpha_code] and
 [CurrencyCode::numeric_code] methods cannot be excluded.
 
-* `serde` - Enables serialization of the [CurrencyCode] type.
+* `serde` - Enables serialization of the [CurrencyCode] type, as its alphabetic
+  code, and adds the `numeric` module for use with `#[serde(with = "...")]`.
 * `currency_name` - Adds the [CurrencyCode::currency_name] method.
 * `country_name` - Adds the [CurrencyCode::country_name] method.
 * `monetary_units` - Adds the [CurrencyCode::monetary_units] method.
 * `is_fund` - Adds the [CurrencyCode::is_fund] method.
-* `historical_codes` - Adds the [CurrencyCode::is_historical] and [CurrencyCode::withdrawal_date] methods.
+* `historical_codes` - Adds the [CurrencyCode::is_historical], [CurrencyCode::withdrawal_date],
+  [CurrencyCode::introduction_date], [CurrencyCode::valid_on], and [CurrencyCode::successor]
+  methods (requires `chrono`).
 * `symbols` - Adds the [CurrencyCode::currency_symbol_str] and [CurrencyCode::currency_symbol_code_points] methods.
+* `countries` - Adds the `CurrencyCode::countries` method and the `currencies_for`
+  function, mapping between currencies and `codes_iso_3166::part_1::CountryCode`.
+* `money` - Adds the `money` module with the `Money` amount type (implies `monetary_units`).
 
 */
 
@@ -91,7 +98,8 @@ By default only the `serde` feature is enabled, the [CurrencyCode::alpha_code] a
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -109,3 +117,178 @@ By default only the `serde` feature is enabled, the [CurrencyCode::alpha_code] a
 // ------------------------------------------------------------------------------------------------
 
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "money")]
+pub mod money;
+
+#[cfg(feature = "serde")]
+pub mod numeric;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "countries")]
+    use codes_iso_3166::part_1::CountryCode;
+
+    #[cfg(feature = "historical_codes")]
+    use chrono::NaiveDate;
+
+    #[cfg(feature = "countries")]
+    #[test]
+    fn test_countries() {
+        assert_eq!(CurrencyCode::BZD.countries(), &[CountryCode::BZ]);
+        assert!(CurrencyCode::EUR.countries().contains(&CountryCode::AX));
+        assert!(CurrencyCode::EUR.countries().contains(&CountryCode::FR));
+        assert!(CurrencyCode::USD.countries().contains(&CountryCode::TL));
+        assert!(CurrencyCode::KPW.countries().contains(&CountryCode::KP));
+        assert!(CurrencyCode::XDR.countries().is_empty());
+    }
+
+    #[cfg(feature = "countries")]
+    #[test]
+    fn test_currencies_for() {
+        let currencies = currencies_for(CountryCode::US);
+        assert_eq!(currencies.legal_tender(), &[CurrencyCode::USD]);
+        assert_eq!(currencies.funds(), &[CurrencyCode::USN]);
+
+        let currencies = currencies_for(CountryCode::BO);
+        assert_eq!(currencies.legal_tender(), &[CurrencyCode::BOB]);
+        assert_eq!(currencies.funds(), &[CurrencyCode::BOV]);
+
+        let currencies = currencies_for(CountryCode::AQ);
+        assert!(currencies.legal_tender().is_empty());
+        assert!(currencies.funds().is_empty());
+    }
+
+    #[cfg(feature = "historical_codes")]
+    #[test]
+    fn test_withdrawal_date() {
+        assert_eq!(CurrencyCode::EUR.withdrawal_date(), None);
+        assert_eq!(
+            CurrencyCode::DEM.withdrawal_date(),
+            NaiveDate::from_ymd_opt(2002, 3, 1)
+        );
+        // "1989 to 1990"
+        assert_eq!(
+            CurrencyCode::YUD.withdrawal_date(),
+            NaiveDate::from_ymd_opt(1990, 1, 1)
+        );
+    }
+
+    #[cfg(feature = "historical_codes")]
+    #[test]
+    fn test_valid_on() {
+        let date = NaiveDate::from_ymd_opt(2001, 6, 30).unwrap();
+        assert!(CurrencyCode::DEM.valid_on(date));
+        assert!(CurrencyCode::EUR.valid_on(date));
+
+        let date = NaiveDate::from_ymd_opt(2003, 1, 1).unwrap();
+        assert!(!CurrencyCode::DEM.valid_on(date));
+        assert!(CurrencyCode::EUR.valid_on(date));
+
+        let date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+        assert!(CurrencyCode::DEM.valid_on(date));
+        assert!(!CurrencyCode::EUR.valid_on(date));
+        assert!(!CurrencyCode::EUR.valid_on(NaiveDate::from_ymd_opt(1998, 12, 31).unwrap()));
+        assert!(CurrencyCode::EUR.valid_on(NaiveDate::from_ymd_opt(1999, 1, 1).unwrap()));
+
+        // VEF replaced VEB in 2008, and was itself withdrawn in 2018.
+        assert!(!CurrencyCode::VEF.valid_on(NaiveDate::from_ymd_opt(2007, 12, 31).unwrap()));
+        assert!(CurrencyCode::VEF.valid_on(NaiveDate::from_ymd_opt(2012, 1, 1).unwrap()));
+        assert!(!CurrencyCode::VEF.valid_on(NaiveDate::from_ymd_opt(2019, 1, 1).unwrap()));
+    }
+
+    #[cfg(feature = "historical_codes")]
+    #[test]
+    fn test_introduction_date() {
+        assert_eq!(
+            CurrencyCode::EUR.introduction_date(),
+            NaiveDate::from_ymd_opt(1999, 1, 1)
+        );
+        assert_eq!(
+            CurrencyCode::ZWL.introduction_date(),
+            NaiveDate::from_ymd_opt(2009, 2, 2)
+        );
+        assert_eq!(CurrencyCode::USD.introduction_date(), None);
+    }
+
+    #[cfg(feature = "historical_codes")]
+    #[test]
+    fn test_successor() {
+        let successor = CurrencyCode::DEM.successor().unwrap();
+        assert_eq!(successor.currency(), CurrencyCode::EUR);
+        assert_eq!(
+            successor.date(),
+            NaiveDate::from_ymd_opt(1999, 1, 1).unwrap()
+        );
+        assert_eq!(CurrencyCode::EUR.successor(), None);
+
+        assert_eq!(
+            CurrencyCode::ZWD.successor().map(|s| s.currency()),
+            Some(CurrencyCode::ZWN)
+        );
+        let latest = CurrencyCode::ZWD.latest_successor().unwrap();
+        assert_eq!(latest.currency(), CurrencyCode::ZWL);
+        assert_eq!(latest.date(), NaiveDate::from_ymd_opt(2009, 2, 2).unwrap());
+
+        // successors are not published by ISO, and the table is incomplete.
+        assert!(CurrencyCode::BRR.is_historical());
+        assert_eq!(CurrencyCode::BRR.successor(), None);
+    }
+
+    #[cfg(feature = "monetary_units")]
+    #[test]
+    fn test_monetary_units() {
+        assert_eq!(CurrencyCode::JPY.monetary_units(), Some(0));
+        assert_eq!(CurrencyCode::KRW.monetary_units(), Some(0));
+        assert_eq!(CurrencyCode::USD.monetary_units(), Some(2));
+        assert_eq!(CurrencyCode::BHD.monetary_units(), Some(3));
+        assert_eq!(CurrencyCode::XAU.monetary_units(), None);
+    }
+
+    #[cfg(all(feature = "monetary_units", feature = "historical_codes"))]
+    #[test]
+    fn test_active_entry_not_replaced_by_historical() {
+        // list-three withdraws EUR for Serbia and Montenegro, without minor units.
+        assert!(!CurrencyCode::EUR.is_historical());
+        assert_eq!(CurrencyCode::EUR.monetary_units(), Some(2));
+        assert!(CurrencyCode::DEM.is_historical());
+    }
+
+    #[test]
+    fn test_from_numeric_code() {
+        assert_eq!(
+            CurrencyCode::from_numeric_code(840),
+            Some(CurrencyCode::USD)
+        );
+        assert_eq!(
+            CurrencyCode::from_numeric_code(978),
+            Some(CurrencyCode::EUR)
+        );
+        // shared with the historical code BUK.
+        assert_eq!(
+            CurrencyCode::from_numeric_code(104),
+            Some(CurrencyCode::MMK)
+        );
+        assert_eq!(CurrencyCode::from_numeric_code(1), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        for code in ALL_CODES {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(json, format!("\"{code}\""));
+            assert_eq!(serde_json::from_str::<CurrencyCode>(&json).unwrap(), code);
+        }
+    }
+}
@@ -0,0 +1,734 @@
+/*!
+Provides a [Money] type that represents an amount as an integer number of
+minor units in a specific [CurrencyCode].
+
+The scale of an amount, the number of decimal places, comes from
+[CurrencyCode::monetary_units] so that, for example, `USD 12.34` is stored as
+1234 cents and `JPY 1234` as 1234 yen. Currencies with no minor units defined
+by the standard, such as `XAU` (Gold), cannot be represented and will result in
+the error [MoneyError::NoMinorUnits].
+
+Arithmetic between amounts is only supported where both share a currency,
+operations return a [MoneyError::CurrencyMismatch] error rather than silently
+combining different currencies.
+
+# Example
+
+```rust
+use codes_iso_4217::CurrencyCode;
+use codes_iso_4217::money::{Money, RoundingMode};
+use std::str::FromStr;
+
+let price = Money::from_str("USD 100.00").unwrap();
+let tax = price.multiply(825, 10000, RoundingMode::HalfEven).unwrap();
+assert_eq!(tax.to_string(), "USD 8.25");
+
+let total = price.checked_add(&tax).unwrap();
+assert_eq!(total.minor_units(), 10825);
+
+let shares = total.allocate(&[1, 1, 1]).unwrap();
+assert_eq!(
+    shares.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+    vec!["USD 36.09", "USD 36.08", "USD 36.08"]
+);
+
+let yen = Money::parse_amount("1234", CurrencyCode::JPY).unwrap();
+assert!(price.checked_add(&yen).is_err());
+
+assert!(Money::new(1, CurrencyCode::XAU).is_err());
+```
+
+*/
+
+use crate::{CurrencyCode, CurrencyCodeError};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An amount of money, stored as an integer number of the minor units of
+/// its currency.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(try_from = "UncheckedMoney")
+)]
+pub struct Money {
+    minor_units: i64,
+    currency: CurrencyCode,
+}
+
+///
+/// The rounding to apply when an operation results in a fraction of a
+/// minor unit.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round away from zero.
+    Up,
+    /// Round towards zero, truncating any fraction.
+    Down,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round to the nearest value, halves are rounded away from zero.
+    HalfUp,
+    /// Round to the nearest value, halves are rounded towards zero.
+    HalfDown,
+    /// Round to the nearest value, halves are rounded to the nearest even
+    /// value; also known as *banker's rounding*.
+    HalfEven,
+}
+
+///
+/// Errors resulting from the creation of, or operations on, [Money] values.
+///
+#[derive(Debug)]
+pub enum MoneyError {
+    /// The currency has no minor units defined, for example `XAU`.
+    NoMinorUnits { currency: CurrencyCode },
+    /// An operation was attempted on amounts in two different currencies.
+    CurrencyMismatch {
+        expecting: CurrencyCode,
+        got: CurrencyCode,
+    },
+    /// The string could not be parsed as an amount in the currency.
+    InvalidAmount { value: String },
+    /// The currency code in a string could not be parsed.
+    InvalidCurrency(CurrencyCodeError),
+    /// The result of an operation is too large to be represented.
+    Overflow,
+    /// The ratios, or number of parts, provided to an allocation are invalid.
+    InvalidAllocation,
+    /// An operation was attempted with a denominator of zero.
+    DivisionByZero,
+}
+
+///
+/// A Result type that specifically uses this module's Error.
+///
+pub type Result<T> = std::result::Result<T, MoneyError>;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// The serialized form of Money, deserialized before validation by Money::new.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct UncheckedMoney {
+    minor_units: i64,
+    currency: CurrencyCode,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.currency, self.amount_string())
+    }
+}
+
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    ///
+    /// Parse a string of the form `"USD 12.34"`, the currency code followed
+    /// by whitespace and the amount.
+    ///
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().split_once(char::is_whitespace) {
+            Some((currency, amount)) => {
+                let currency =
+                    CurrencyCode::from_str(currency).map_err(MoneyError::InvalidCurrency)?;
+                Self::parse_amount(amount.trim(), currency)
+            }
+            None => Err(invalid_amount(s)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedMoney> for Money {
+    type Error = MoneyError;
+
+    fn try_from(value: UncheckedMoney) -> Result<Self> {
+        Self::new(value.minor_units, value.currency)
+    }
+}
+
+impl PartialOrd for Money {
+    ///
+    /// Amounts are only comparable if they are in the same currency.
+    ///
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency == other.currency {
+            Some(self.minor_units.cmp(&other.minor_units))
+        } else {
+            None
+        }
+    }
+}
+
+impl Money {
+    ///
+    /// Create a new amount from a number of minor units, for example
+    /// `Money::new(1234, CurrencyCode::USD)` is `USD 12.34`.
+    ///
+    pub fn new(minor_units: i64, currency: CurrencyCode) -> Result<Self> {
+        let _ = scale_of(currency)?;
+        Ok(Self {
+            minor_units,
+            currency,
+        })
+    }
+
+    ///
+    /// Create a zero amount in the provided currency.
+    ///
+    pub fn zero(currency: CurrencyCode) -> Result<Self> {
+        Self::new(0, currency)
+    }
+
+    ///
+    /// Parse an amount, such as `"-12.34"`, in the provided currency. The
+    /// amount may have fewer decimal places than the currency's scale but an
+    /// error is returned if it has more, see [Money::parse_amount_rounded].
+    ///
+    pub fn parse_amount(s: &str, currency: CurrencyCode) -> Result<Self> {
+        let scale = scale_of(currency)?;
+        let (value, decimals) = parse_decimal(s)?;
+        if decimals > scale as u32 {
+            Err(invalid_amount(s))
+        } else {
+            let minor_units = value
+                .checked_mul(10_i128.pow(scale as u32 - decimals))
+                .ok_or(MoneyError::Overflow)?;
+            Self::from_i128(minor_units, currency)
+        }
+    }
+
+    ///
+    /// Parse an amount, such as `"12.345"`, in the provided currency. Any
+    /// decimal places beyond the currency's scale are rounded according to
+    /// `mode`.
+    ///
+    pub fn parse_amount_rounded(
+        s: &str,
+        currency: CurrencyCode,
+        mode: RoundingMode,
+    ) -> Result<Self> {
+        let scale = scale_of(currency)?;
+        let (value, decimals) = parse_decimal(s)?;
+        let minor_units = if decimals > scale as u32 {
+            let divisor = 10_i128
+                .checked_pow(decimals - scale as u32)
+                .ok_or(MoneyError::Overflow)?;
+            divide_rounded(value, divisor, mode)
+        } else {
+            value
+                .checked_mul(10_i128.pow(scale as u32 - decimals))
+                .ok_or(MoneyError::Overflow)?
+        };
+        Self::from_i128(minor_units, currency)
+    }
+
+    /// Returns the currency of this amount.
+    pub const fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    /// Returns this amount as an integer number of minor units.
+    pub const fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Returns the number of decimal places used by this amount's currency.
+    pub fn scale(&self) -> u8 {
+        self.currency.monetary_units().unwrap_or_default()
+    }
+
+    /// Returns `true` if this amount is zero, else `false`.
+    pub const fn is_zero(&self) -> bool {
+        self.minor_units == 0
+    }
+
+    /// Returns `true` if this amount is less than zero, else `false`.
+    pub const fn is_negative(&self) -> bool {
+        self.minor_units < 0
+    }
+
+    ///
+    /// Returns the amount, without the currency code, formatted with the
+    /// currency's scale; for example `"-12.30"` or, for `JPY`, `"1234"`.
+    ///
+    pub fn amount_string(&self) -> String {
+        let scale = self.scale() as u32;
+        let sign = if self.is_negative() { "-" } else { "" };
+        let value = self.minor_units.unsigned_abs();
+        if scale == 0 {
+            format!("{}{}", sign, value)
+        } else {
+            let divisor = 10_u64.pow(scale);
+            format!(
+                "{}{}.{:0>width$}",
+                sign,
+                value / divisor,
+                value % divisor,
+                width = scale as usize
+            )
+        }
+    }
+
+    ///
+    /// Add `other` to this amount, both must have the same currency.
+    ///
+    pub fn checked_add(&self, other: &Self) -> Result<Self> {
+        self.same_currency(other)?;
+        self.with_minor_units(self.minor_units.checked_add(other.minor_units))
+    }
+
+    ///
+    /// Subtract `other` from this amount, both must have the same currency.
+    ///
+    pub fn checked_sub(&self, other: &Self) -> Result<Self> {
+        self.same_currency(other)?;
+        self.with_minor_units(self.minor_units.checked_sub(other.minor_units))
+    }
+
+    ///
+    /// Negate this amount.
+    ///
+    pub fn checked_neg(&self) -> Result<Self> {
+        self.with_minor_units(self.minor_units.checked_neg())
+    }
+
+    ///
+    /// Multiply this amount by an integer value.
+    ///
+    pub fn checked_mul(&self, multiplier: i64) -> Result<Self> {
+        self.with_minor_units(self.minor_units.checked_mul(multiplier))
+    }
+
+    ///
+    /// Multiply this amount by the fraction `numerator / denominator`, such
+    /// as a rate, rounding any fraction of a minor unit according to `mode`.
+    /// A `denominator` of zero results in the error [MoneyError::DivisionByZero].
+    ///
+    pub fn multiply(&self, numerator: i64, denominator: i64, mode: RoundingMode) -> Result<Self> {
+        if denominator == 0 {
+            return Err(MoneyError::DivisionByZero);
+        }
+        let (numerator, denominator) = if denominator < 0 {
+            (-(numerator as i128), -(denominator as i128))
+        } else {
+            (numerator as i128, denominator as i128)
+        };
+        let product = self.minor_units as i128 * numerator;
+        Self::from_i128(divide_rounded(product, denominator, mode), self.currency)
+    }
+
+    ///
+    /// Round this amount to a number of decimal places less than the
+    /// currency's scale, for example to remove cents for cash payments. The
+    /// result keeps the currency's scale.
+    ///
+    pub fn round(&self, decimals: u8, mode: RoundingMode) -> Result<Self> {
+        let scale = self.scale();
+        if decimals >= scale {
+            Ok(*self)
+        } else {
+            let factor = 10_i128.pow((scale - decimals) as u32);
+            let rounded = divide_rounded(self.minor_units as i128, factor, mode) * factor;
+            Self::from_i128(rounded, self.currency)
+        }
+    }
+
+    ///
+    /// Allocate this amount across a number of parts in proportion to the
+    /// provided `ratios`. No minor units are lost, any remainder is
+    /// distributed one unit at a time starting with the first part.
+    ///
+    pub fn allocate(&self, ratios: &[u32]) -> Result<Vec<Self>> {
+        let total: u64 = ratios.iter().map(|r| *r as u64).sum();
+        if ratios.is_empty() || total == 0 {
+            return Err(MoneyError::InvalidAllocation);
+        }
+        let amount = self.minor_units as i128;
+        let mut parts: Vec<i128> = ratios
+            .iter()
+            .map(|ratio| amount * *ratio as i128 / total as i128)
+            .collect();
+        let mut remainder = amount - parts.iter().sum::<i128>();
+        let step = remainder.signum();
+        let mut index = 0;
+        while remainder != 0 {
+            if ratios[index] > 0 {
+                parts[index] += step;
+                remainder -= step;
+            }
+            index = (index + 1) % parts.len();
+        }
+        parts
+            .into_iter()
+            .map(|part| Self::from_i128(part, self.currency))
+            .collect()
+    }
+
+    ///
+    /// Split this amount into `parts` equal parts, as far as possible; see
+    /// [Money::allocate].
+    ///
+    pub fn split(&self, parts: usize) -> Result<Vec<Self>> {
+        self.allocate(&vec![1; parts])
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn same_currency(&self, other: &Self) -> Result<()> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                expecting: self.currency,
+                got: other.currency,
+            })
+        }
+    }
+
+    fn with_minor_units(&self, minor_units: Option<i64>) -> Result<Self> {
+        Ok(Self {
+            minor_units: minor_units.ok_or(MoneyError::Overflow)?,
+            currency: self.currency,
+        })
+    }
+
+    fn from_i128(minor_units: i128, currency: CurrencyCode) -> Result<Self> {
+        Ok(Self {
+            minor_units: i64::try_from(minor_units).map_err(|_| MoneyError::Overflow)?,
+            currency,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for MoneyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoneyError::NoMinorUnits { currency } => write!(
+                f,
+                "The currency {} has no minor units and cannot be used for amounts",
+                currency
+            ),
+            MoneyError::CurrencyMismatch { expecting, got } => write!(
+                f,
+                "Operation on different currencies, expecting {}, got {}",
+                expecting, got
+            ),
+            MoneyError::InvalidAmount { value } => {
+                write!(f, "The value {:?} is not a valid amount", value)
+            }
+            MoneyError::InvalidCurrency(e) => write!(f, "Invalid currency, {}", e),
+            MoneyError::Overflow => write!(f, "The amount is too large to be represented"),
+            MoneyError::InvalidAllocation => {
+                write!(f, "Allocation requires at least one non-zero ratio")
+            }
+            MoneyError::DivisionByZero => write!(f, "Attempted to divide by zero"),
+        }
+    }
+}
+
+impl std::error::Error for MoneyError {}
+
+impl From<CurrencyCodeError> for MoneyError {
+    fn from(e: CurrencyCodeError) -> Self {
+        Self::InvalidCurrency(e)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn invalid_amount(s: &str) -> MoneyError {
+    MoneyError::InvalidAmount {
+        value: s.to_string(),
+    }
+}
+
+fn scale_of(currency: CurrencyCode) -> Result<u8> {
+    currency
+        .monetary_units()
+        .ok_or(MoneyError::NoMinorUnits { currency })
+}
+
+// Returns the digits of `s` as an integer and the number of decimal places.
+fn parse_decimal(s: &str) -> Result<(i128, u32)> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (whole, fraction) = match unsigned.split_once('.') {
+        Some((whole, fraction)) if !fraction.is_empty() => (whole, fraction),
+        Some(_) => return Err(invalid_amount(s)),
+        None => (unsigned, ""),
+    };
+    if whole.is_empty()
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid_amount(s));
+    }
+    let value =
+        i128::from_str(&format!("{}{}", whole, fraction)).map_err(|_| MoneyError::Overflow)?;
+    Ok((if negative { -value } else { value }, fraction.len() as u32))
+}
+
+// Divide `value` by the positive `divisor`, rounding according to `mode`.
+fn divide_rounded(value: i128, divisor: i128, mode: RoundingMode) -> i128 {
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    if remainder == 0 {
+        return quotient;
+    }
+    let away = quotient + value.signum();
+    let half = (remainder.abs() * 2).cmp(&divisor);
+    match mode {
+        RoundingMode::Up => away,
+        RoundingMode::Down => quotient,
+        RoundingMode::Ceiling if value > 0 => away,
+        RoundingMode::Ceiling => quotient,
+        RoundingMode::Floor if value < 0 => away,
+        RoundingMode::Floor => quotient,
+        RoundingMode::HalfUp if half == Ordering::Less => quotient,
+        RoundingMode::HalfUp => away,
+        RoundingMode::HalfDown if half == Ordering::Greater => away,
+        RoundingMode::HalfDown => quotient,
+        RoundingMode::HalfEven => match half {
+            Ordering::Less => quotient,
+            Ordering::Greater => away,
+            Ordering::Equal if quotient % 2 == 0 => quotient,
+            Ordering::Equal => away,
+        },
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let money = Money::from_str("USD 12.3").unwrap();
+        assert_eq!(money.minor_units(), 1230);
+        assert_eq!(money.to_string(), "USD 12.30");
+
+        let money = Money::parse_amount("-0.05", CurrencyCode::EUR).unwrap();
+        assert_eq!(money.to_string(), "EUR -0.05");
+
+        let money = Money::parse_amount("1234", CurrencyCode::JPY).unwrap();
+        assert_eq!(money.to_string(), "JPY 1234");
+
+        let money = Money::parse_amount("1.234", CurrencyCode::BHD).unwrap();
+        assert_eq!(money.minor_units(), 1234);
+        assert_eq!(money.amount_string(), "1.234");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Money::parse_amount("1.234", CurrencyCode::USD),
+            Err(MoneyError::InvalidAmount { .. })
+        ));
+        for s in ["", "-", "1.", ".5", "1,00", "1.2.3", "USD"] {
+            assert!(
+                Money::parse_amount(s, CurrencyCode::USD).is_err(),
+                "{:?}",
+                s
+            );
+        }
+        assert!(matches!(
+            Money::from_str("ABC 1.00"),
+            Err(MoneyError::InvalidCurrency(_))
+        ));
+        assert!(matches!(
+            Money::parse_amount("1", CurrencyCode::XAU),
+            Err(MoneyError::NoMinorUnits {
+                currency: CurrencyCode::XAU
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_rounded() {
+        let money = Money::parse_amount_rounded("1.235", CurrencyCode::USD, RoundingMode::HalfEven)
+            .unwrap();
+        assert_eq!(money.minor_units(), 124);
+        let money = Money::parse_amount_rounded("1.225", CurrencyCode::USD, RoundingMode::HalfEven)
+            .unwrap();
+        assert_eq!(money.minor_units(), 122);
+        let money =
+            Money::parse_amount_rounded("-1.225", CurrencyCode::USD, RoundingMode::HalfUp).unwrap();
+        assert_eq!(money.minor_units(), -123);
+
+        let too_precise = format!("0.{}1", "0".repeat(40));
+        assert!(matches!(
+            Money::parse_amount_rounded(&too_precise, CurrencyCode::USD, RoundingMode::Up),
+            Err(MoneyError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Money::new(150, CurrencyCode::USD).unwrap();
+        let b = Money::new(275, CurrencyCode::USD).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().minor_units(), 425);
+        assert_eq!(a.checked_sub(&b).unwrap().minor_units(), -125);
+        assert_eq!(a.checked_mul(3).unwrap().minor_units(), 450);
+        assert_eq!(a.checked_neg().unwrap().minor_units(), -150);
+        assert!(a < b);
+
+        let c = Money::new(150, CurrencyCode::EUR).unwrap();
+        assert!(matches!(
+            a.checked_add(&c),
+            Err(MoneyError::CurrencyMismatch {
+                expecting: CurrencyCode::USD,
+                got: CurrencyCode::EUR
+            })
+        ));
+        assert_eq!(a.partial_cmp(&c), None);
+
+        let max = Money::new(i64::MAX, CurrencyCode::USD).unwrap();
+        assert!(matches!(max.checked_add(&a), Err(MoneyError::Overflow)));
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let cases = [
+            (RoundingMode::Up, [3, 2, 2, -2, -2, -3]),
+            (RoundingMode::Down, [2, 2, 1, -1, -2, -2]),
+            (RoundingMode::Ceiling, [3, 2, 2, -1, -2, -2]),
+            (RoundingMode::Floor, [2, 2, 1, -2, -2, -3]),
+            (RoundingMode::HalfUp, [3, 2, 2, -2, -2, -3]),
+            (RoundingMode::HalfDown, [2, 2, 2, -2, -2, -2]),
+            (RoundingMode::HalfEven, [2, 2, 2, -2, -2, -2]),
+        ];
+        // 2.5, 2.0, 1.6, -1.6, -2.0, -2.5
+        let values = [25, 20, 16, -16, -20, -25];
+        for (mode, expected) in cases {
+            for (value, expected) in values.iter().zip(expected) {
+                assert_eq!(
+                    divide_rounded(*value, 10, mode),
+                    expected,
+                    "{:?} {}",
+                    mode,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiply_and_round() {
+        let money = Money::new(1000, CurrencyCode::USD).unwrap();
+        assert_eq!(
+            money
+                .multiply(1, 3, RoundingMode::HalfUp)
+                .unwrap()
+                .minor_units(),
+            333
+        );
+        assert_eq!(
+            money
+                .multiply(2, -3, RoundingMode::Up)
+                .unwrap()
+                .minor_units(),
+            -667
+        );
+
+        let money = Money::new(1050, CurrencyCode::USD).unwrap();
+        assert_eq!(
+            money
+                .round(0, RoundingMode::HalfEven)
+                .unwrap()
+                .minor_units(),
+            1000
+        );
+        assert_eq!(
+            money.round(0, RoundingMode::HalfUp).unwrap().minor_units(),
+            1100
+        );
+
+        assert!(matches!(
+            money.multiply(1, 0, RoundingMode::HalfUp),
+            Err(MoneyError::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn test_allocate() {
+        let money = Money::new(100, CurrencyCode::USD).unwrap();
+        let parts = money.allocate(&[70, 30]).unwrap();
+        assert_eq!(
+            parts.iter().map(Money::minor_units).collect::<Vec<_>>(),
+            vec![70, 30]
+        );
+
+        let money = Money::new(5, CurrencyCode::USD).unwrap();
+        let parts = money.allocate(&[3, 7]).unwrap();
+        assert_eq!(
+            parts.iter().map(Money::minor_units).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+
+        let money = Money::new(-100, CurrencyCode::USD).unwrap();
+        let parts = money.split(3).unwrap();
+        assert_eq!(
+            parts.iter().map(Money::minor_units).collect::<Vec<_>>(),
+            vec![-34, -33, -33]
+        );
+
+        let parts = money.allocate(&[0, 1, 0]).unwrap();
+        assert_eq!(
+            parts.iter().map(Money::minor_units).collect::<Vec<_>>(),
+            vec![0, -100, 0]
+        );
+
+        assert!(matches!(
+            money.allocate(&[]),
+            Err(MoneyError::InvalidAllocation)
+        ));
+        assert!(matches!(money.split(0), Err(MoneyError::InvalidAllocation)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let money = Money::new(1234, CurrencyCode::USD).unwrap();
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"minor_units":1234,"currency":"USD"}"#);
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+
+        assert!(serde_json::from_str::<Money>(r#"{"minor_units":1,"currency":"XAU"}"#).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+/*!
+Provides functions for use with the `#[serde(with = "...")]` attribute to
+represent a [CurrencyCode] by its numeric code rather than its alphabetic
+code.
+
+Codes are serialized as integers, but may be deserialized from either an
+integer or a string of digits such as `"036"`. Where an active and an
+historical code share a numeric code, deserialization returns the active code.
+
+# Example
+
+```rust
+use codes_iso_4217::CurrencyCode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Payment {
+    #[serde(with = "codes_iso_4217::numeric")]
+    currency: CurrencyCode,
+}
+
+let payment = Payment { currency: CurrencyCode::EUR };
+let json = serde_json::to_string(&payment).unwrap();
+assert_eq!(json, r#"{"currency":978}"#);
+assert_eq!(serde_json::from_str::<Payment>(&json).unwrap(), payment);
+```
+
+*/
+
+use crate::CurrencyCode;
+use codes_common::serde_helpers::deserialize_numeric_code;
+use serde::de::{Error as _, Unexpected};
+use serde::ser::Error as _;
+use serde::{Deserializer, Serializer};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serialize `code` as its numeric code, this is an error for codes without
+/// a numeric code.
+///
+pub fn serialize<S>(code: &CurrencyCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match code.numeric_code() {
+        Some(numeric_code) => serializer.serialize_u16(numeric_code),
+        None => Err(S::Error::custom(format!(
+            "currency code {code} has no numeric code"
+        ))),
+    }
+}
+
+///
+/// Deserialize a [CurrencyCode] from its numeric code.
+///
+pub fn deserialize<'de, D>(deserializer: D) -> Result<CurrencyCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let numeric_code = deserialize_numeric_code(deserializer)?;
+    CurrencyCode::from_numeric_code(numeric_code).ok_or_else(|| {
+        D::Error::invalid_value(
+            Unexpected::Unsigned(numeric_code.into()),
+            &"an ISO 4217 numeric code",
+        )
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper(#[serde(with = "super")] CurrencyCode);
+
+    #[test]
+    fn test_numeric_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Wrapper(CurrencyCode::USD)).unwrap(),
+            "840"
+        );
+        assert_eq!(
+            serde_json::to_string(&Wrapper(CurrencyCode::AUD)).unwrap(),
+            "36"
+        );
+    }
+
+    #[test]
+    fn test_numeric_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Wrapper>("840").unwrap().0,
+            CurrencyCode::USD
+        );
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""036""#).unwrap().0,
+            CurrencyCode::AUD
+        );
+        assert!(serde_json::from_str::<Wrapper>(r#""USD""#).is_err());
+        assert!(serde_json::from_str::<Wrapper>("1").is_err());
+    }
+}
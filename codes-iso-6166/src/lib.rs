@@ -65,10 +65,16 @@ assert_eq!(&format!("{:#}", bae_systems), "GB-000263494-6");
 
 # Features
 
-By default only the `serde` feature is enabled.
+By default the `serde` and `nsin` features are enabled.
 
 * `serde` - Enables serialization of the [InternationalSecuritiesId] type.
 * `url` - Enables the conversion between ISIN and URL (URN) forms.
+* `nsin` - Enables all of the national number schemes in the [nsin] module,
+  which are used by [InternationalSecuritiesId::from_str_strict].
+  * `nsin_cusip` - CUSIP, for the United States and Canada.
+  * `nsin_sedol` - SEDOL, for the United Kingdom and Ireland.
+  * `nsin_valoren` - Valor, for Switzerland and Liechtenstein.
+  * `nsin_wkn` - WKN, for Germany.
 
 */
 
@@ -101,7 +107,8 @@ By default only the `serde` feature is enabled.
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -117,6 +124,7 @@ use codes_check_digits::{luhn, Calculator};
 use codes_common::error::{invalid_format, invalid_length};
 use codes_common::{fixed_length_code, Code};
 use codes_iso_3166::part_1::CountryCode;
+use nsin::NationalSecuritiesNumber;
 use std::{fmt::Display, fmt::Formatter, str::FromStr};
 use tracing::warn;
 
@@ -138,7 +146,10 @@ pub const ISO_6166: Standard = Standard::new_with_long_ref(
     "ISO 6166-1:2021",
     "Financial services — International securities identification number (ISIN)",
     "https://www.iso.org/standard/78502.html",
-);
+)
+.with_edition("2021")
+.with_publication_date("2021-08")
+.with_registration_authority(Agency::ANNA);
 
 ///
 /// The ISO 6166 International Securities Identification
@@ -216,7 +227,6 @@ impl FromStr for InternationalSecuritiesId {
             let nsin = &s[2..11];
             Ok(InternationalSecuritiesId {
                 country: country_code,
-                // old: nsin: validate_nsin(&country_code, nsid)?,
                 nsin: nsin.to_string(),
                 check_digit: u8::from_str(&s[11..]).map_err(|_| invalid_format(TYPE_NAME, s))?,
             })
@@ -278,6 +288,17 @@ impl InternationalSecuritiesId {
         })
     }
 
+    ///
+    /// Construct a new ISIN from its string form, as `FromStr` does, but
+    /// also validate the national number according to the scheme used in
+    /// the ISIN's country, if one is known.
+    ///
+    pub fn from_str_strict(s: &str) -> Result<Self, InternationalSecuritiesIdError> {
+        let isin = Self::from_str(s)?;
+        let _ = isin.typed_national_number()?;
+        Ok(isin)
+    }
+
     ///
     /// Return the country code of this ISIN.
     ///
@@ -292,6 +313,18 @@ impl InternationalSecuritiesId {
         &self.nsin
     }
 
+    ///
+    /// Return the NSIN portion of this ISIN parsed according to the scheme
+    /// used in the ISIN's country, or `None` if no scheme is known.
+    ///
+    pub fn typed_national_number(
+        &self,
+    ) -> Result<Option<NationalSecuritiesNumber>, InternationalSecuritiesIdError> {
+        nsin::national_number_scheme_for(&self.country)
+            .map(|scheme| scheme.parse_padded(format!("{:0>9}", self.nsin)))
+            .transpose()
+    }
+
     ///
     /// Return the check digit of this ISIN.
     ///
@@ -300,28 +333,6 @@ impl InternationalSecuritiesId {
     }
 }
 
-// ------------------------------------------------------------------------------------------------
-// Private Functions
-// ------------------------------------------------------------------------------------------------
-
-///
-/// Wrapper to fetch an NSIN scheme for the provided country code
-/// and, if one exists, validate it.
-///
-#[cfg(feature = "old_nsin")]
-fn validate_nsin(country: &CountryCode, s: &str) -> Result<String, InternationalSecuritiesIdError> {
-    if let Some(nsin) = nsin::national_number_scheme_for(country) {
-        if nsin.is_valid(s) {
-            Ok(s.to_string())
-        } else {
-            warn!("NSID value {:?} is not a valid {}", s, nsin.name());
-            Err(invalid_format(nsin.name(), s))
-        }
-    } else {
-        Ok(s.to_string())
-    }
-}
-
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -388,6 +399,29 @@ mod tests {
         assert_eq!(format!("{:#}", isin), "AU-0000XVGZA-3".to_string());
     }
 
+    #[cfg(feature = "nsin")]
+    #[test]
+    fn test_from_str_strict() {
+        assert!(InternationalSecuritiesId::from_str_strict("US0378331005").is_ok());
+        assert!(InternationalSecuritiesId::from_str_strict("GB0002634946").is_ok());
+        assert!(InternationalSecuritiesId::from_str_strict("AU0000XVGZA3").is_ok());
+        // A valid ISIN check digit, but not a valid CUSIP check digit.
+        assert!(InternationalSecuritiesId::from_str("US0378331013").is_ok());
+        assert!(InternationalSecuritiesId::from_str_strict("US0378331013").is_err());
+    }
+
+    #[cfg(feature = "nsin")]
+    #[test]
+    fn test_typed_national_number() {
+        let isin = InternationalSecuritiesId::new(CountryCode::CH, "1213853").unwrap();
+        let nsin = isin.typed_national_number().unwrap().unwrap();
+        assert_eq!(nsin.scheme(), nsin::NsinScheme::Valor);
+        assert_eq!(nsin.to_string(), "1213853");
+
+        let isin = InternationalSecuritiesId::new(CountryCode::JP, "K0VF05").unwrap();
+        assert!(matches!(isin.typed_national_number(), Ok(None)));
+    }
+
     #[test]
     fn test_japan() {
         let isin = InternationalSecuritiesId::new(CountryCode::JP, "K0VF05").unwrap();
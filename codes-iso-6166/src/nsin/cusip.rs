@@ -1,18 +1,35 @@
 /*!
-One-line description.
+Provides the [Cusip] type for the 9 character identifiers assigned by the
+CUSIP Services Bureau to securities in the United States and Canada.
 
-More detailed description, with
+A CUSIP is made up of a 6 character issuer number, a 2 character issue
+number, and a single check digit. Issuer and issue numbers are alphanumeric,
+and the characters `'*'`, `'@'`, and `'#'` are reserved for private
+placement numbers.
 
 # Example
 
-YYYYY
+```rust
+use codes_iso_3166::part_1::CountryCode;
+use codes_iso_6166::nsin::cusip::Cusip;
+use std::str::FromStr;
+
+let apple = Cusip::from_str("037833100").unwrap();
+assert_eq!(apple.issuer_number(), "037833");
+assert_eq!(apple.issue_number(), "10");
+assert_eq!(apple.check_digit(), 0);
+
+let isin = apple.to_isin(CountryCode::US).unwrap();
+assert_eq!(isin.to_string(), "US0378331005");
+```
 
 */
 
-use crate::InternationalSecuritiesIdError;
-use codes_check_digits::{luhn, Calculator, CodeWithCheckDigits};
-use codes_common::error::invalid_length;
+use crate::{InternationalSecuritiesId, InternationalSecuritiesIdError};
+use codes_check_digits::{cusip, Calculator, CodeWithCheckDigits};
+use codes_common::error::{invalid_format, invalid_length};
 use codes_common::{code_as_str, code_impl, fixed_length_code, FixedLengthCode};
+use codes_iso_3166::part_1::CountryCode;
 use std::str::FromStr;
 use tracing::warn;
 
@@ -28,6 +45,7 @@ use serde::{Deserialize, Serialize};
 ///
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Cusip(String);
 
 pub const NATIONAL_ASSIGNMENT_AGENCY: &str = "CUSIP Services Bureau";
@@ -42,12 +60,12 @@ code_impl!(Cusip, as_str, str, String, to_string);
 
 code_as_str!(Cusip);
 
-fixed_length_code!(Cusip, 20);
+fixed_length_code!(Cusip, 9);
 
 impl CodeWithCheckDigits for Cusip {
     type CheckDigit = u8;
-    type CheckDigitCalculator = luhn::CheckDigitAlgorithm;
-    const CHECK_DIGIT_ALGORITHM: Self::CheckDigitCalculator = *luhn::get_algorithm_instance();
+    type CheckDigitCalculator = cusip::CheckDigitAlgorithm;
+    const CHECK_DIGIT_ALGORITHM: Self::CheckDigitCalculator = *cusip::get_algorithm_instance();
 }
 
 impl FromStr for Cusip {
@@ -68,3 +86,105 @@ impl FromStr for Cusip {
         }
     }
 }
+
+impl TryFrom<String> for Cusip {
+    type Error = InternationalSecuritiesIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+impl Cusip {
+    ///
+    /// Return the 6 character issuer number, identifying the issuer of
+    /// the security.
+    ///
+    pub fn issuer_number(&self) -> &str {
+        &self.0[0..6]
+    }
+
+    ///
+    /// Return the 2 character issue number, identifying the specific
+    /// security of the issuer.
+    ///
+    pub fn issue_number(&self) -> &str {
+        &self.0[6..8]
+    }
+
+    ///
+    /// Return the check digit of this CUSIP.
+    ///
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+
+    ///
+    /// Construct an ISIN using this CUSIP as the national number, CUSIP
+    /// numbers are only assigned to securities from the United States
+    /// and Canada so any other country code will be rejected.
+    ///
+    pub fn to_isin(
+        &self,
+        country: CountryCode,
+    ) -> Result<InternationalSecuritiesId, InternationalSecuritiesIdError> {
+        match country {
+            CountryCode::US | CountryCode::CA => InternationalSecuritiesId::new(country, &self.0),
+            _ => {
+                warn!(
+                    "{} can only be used in an ISIN for US or CA, not {}",
+                    TYPE_NAME, country
+                );
+                Err(invalid_format(TYPE_NAME, country.to_string()))
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::nsin::cusip::Cusip;
+    use codes_common::Code;
+    use codes_iso_3166::part_1::CountryCode;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_cusip_is_valid() {
+        assert!(Cusip::is_valid("037833100"));
+        assert!(Cusip::is_valid("17275R102"));
+        assert!(!Cusip::is_valid("037833101"));
+        assert!(!Cusip::is_valid("US0378331005"));
+    }
+
+    #[test]
+    fn test_cusip_parts() {
+        let cusip = Cusip::from_str("17275R102").unwrap();
+        assert_eq!(cusip.issuer_number(), "17275R");
+        assert_eq!(cusip.issue_number(), "10");
+        assert_eq!(cusip.check_digit(), 2);
+    }
+
+    #[test]
+    fn test_cusip_to_isin() {
+        let cusip = Cusip::from_str("037833100").unwrap();
+        assert_eq!(
+            cusip.to_isin(CountryCode::US).unwrap().to_string(),
+            "US0378331005"
+        );
+        assert!(cusip.to_isin(CountryCode::GB).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_cusip_serde() {
+        let cusip: Cusip = serde_json::from_str("\"17275R102\"").unwrap();
+        assert_eq!(cusip.check_digit(), 2);
+        assert_eq!(serde_json::to_string(&cusip).unwrap(), "\"17275R102\"");
+        assert!(serde_json::from_str::<Cusip>("\"x\"").is_err());
+        assert!(serde_json::from_str::<Cusip>("\"037833101\"").is_err());
+    }
+}
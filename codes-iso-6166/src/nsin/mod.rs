@@ -1,12 +1,255 @@
 /*!
+Provides types for the National Securities Identifying Numbers (NSIN) that
+form the national number portion of an ISIN.
 
+Each national numbering agency has its own scheme, the function
+[national_number_scheme_for] returns the [NsinScheme] used by a country, if
+one is known, which can then validate and parse national numbers.
+
+# Example
+
+```rust
+# let test_code: &str = "0263494";
+use codes_iso_3166::part_1::CountryCode;
+use codes_iso_6166::nsin::national_number_scheme_for;
+
+if let Some(nsin) = national_number_scheme_for(&CountryCode::GB) {
+    if !nsin.is_valid(test_code) {
+        panic!("Not a valid {} (NSIN).", nsin.name());
+    }
+}
+```
 
 */
 
+use crate::InternationalSecuritiesIdError;
+use codes_iso_3166::part_1::CountryCode;
+use std::fmt::Display;
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// The national numbering schemes known to this package.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NsinScheme {
+    /// CUSIP, used in the United States and Canada.
+    #[cfg(feature = "nsin_cusip")]
+    Cusip,
+    /// SEDOL, used in the United Kingdom and Ireland.
+    #[cfg(feature = "nsin_sedol")]
+    Sedol,
+    /// Valor, used in Switzerland and Liechtenstein.
+    #[cfg(feature = "nsin_valoren")]
+    Valor,
+    /// Wertpapierkennnummer, used in Germany.
+    #[cfg(feature = "nsin_wkn")]
+    Wkn,
+}
+
+///
+/// A national number that has been validated according to its [NsinScheme].
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NationalSecuritiesNumber {
+    #[cfg(feature = "nsin_cusip")]
+    Cusip(cusip::Cusip),
+    #[cfg(feature = "nsin_sedol")]
+    Sedol(sedol::Sedol),
+    #[cfg(feature = "nsin_valoren")]
+    Valor(valoren::Valor),
+    #[cfg(feature = "nsin_wkn")]
+    Wkn(wkn::Wkn),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the national numbering scheme used by the provided country, if
+/// one is known.
+///
+pub fn national_number_scheme_for(country: &CountryCode) -> Option<NsinScheme> {
+    match country {
+        #[cfg(feature = "nsin_cusip")]
+        CountryCode::US | CountryCode::CA => Some(NsinScheme::Cusip),
+        #[cfg(feature = "nsin_sedol")]
+        CountryCode::GB | CountryCode::IE => Some(NsinScheme::Sedol),
+        #[cfg(feature = "nsin_valoren")]
+        CountryCode::CH | CountryCode::LI => Some(NsinScheme::Valor),
+        #[cfg(feature = "nsin_wkn")]
+        CountryCode::DE => Some(NsinScheme::Wkn),
+        _ => None,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for NsinScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl NsinScheme {
+    ///
+    /// Return the common name of this scheme.
+    ///
+    pub fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "nsin_cusip")]
+            Self::Cusip => "CUSIP",
+            #[cfg(feature = "nsin_sedol")]
+            Self::Sedol => "SEDOL",
+            #[cfg(feature = "nsin_valoren")]
+            Self::Valor => "VALOR",
+            #[cfg(feature = "nsin_wkn")]
+            Self::Wkn => "WKN",
+        }
+    }
+
+    ///
+    /// Return the name of the agency that assigns numbers in this scheme.
+    ///
+    pub fn national_assignment_agency(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "nsin_cusip")]
+            Self::Cusip => cusip::NATIONAL_ASSIGNMENT_AGENCY,
+            #[cfg(feature = "nsin_sedol")]
+            Self::Sedol => sedol::NATIONAL_ASSIGNMENT_AGENCY,
+            #[cfg(feature = "nsin_valoren")]
+            Self::Valor => valoren::NATIONAL_ASSIGNMENT_AGENCY,
+            #[cfg(feature = "nsin_wkn")]
+            Self::Wkn => wkn::NATIONAL_ASSIGNMENT_AGENCY,
+        }
+    }
+
+    ///
+    /// Returns `true` if the string is a valid number in this scheme, else
+    /// `false`.
+    ///
+    pub fn is_valid<S>(&self, s: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.parse(s).is_ok()
+    }
+
+    ///
+    /// Parse the string as a number in this scheme.
+    ///
+    #[cfg_attr(
+        not(any(
+            feature = "nsin_cusip",
+            feature = "nsin_sedol",
+            feature = "nsin_valoren",
+            feature = "nsin_wkn"
+        )),
+        allow(unused_variables)
+    )]
+    pub fn parse<S>(&self, s: S) -> Result<NationalSecuritiesNumber, InternationalSecuritiesIdError>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        match *self {
+            #[cfg(feature = "nsin_cusip")]
+            Self::Cusip => s.parse().map(NationalSecuritiesNumber::Cusip),
+            #[cfg(feature = "nsin_sedol")]
+            Self::Sedol => s.parse().map(NationalSecuritiesNumber::Sedol),
+            #[cfg(feature = "nsin_valoren")]
+            Self::Valor => s.parse().map(NationalSecuritiesNumber::Valor),
+            #[cfg(feature = "nsin_wkn")]
+            Self::Wkn => s.parse().map(NationalSecuritiesNumber::Wkn),
+        }
+    }
+
+    ///
+    /// Parse the 9 character, zero padded, national number from an ISIN as
+    /// a number in this scheme.
+    ///
+    pub fn parse_padded<S>(
+        &self,
+        s: S,
+    ) -> Result<NationalSecuritiesNumber, InternationalSecuritiesIdError>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        let (min_length, max_length) = self.length_range();
+        // The number itself may begin with zeros, so never strip below the
+        // minimum length of the scheme.
+        let length = s.trim_start_matches('0').len().max(min_length);
+        if length <= max_length && length <= s.len() {
+            self.parse(&s[(s.len() - length)..])
+        } else {
+            self.parse(s)
+        }
+    }
+
+    fn length_range(&self) -> (usize, usize) {
+        match *self {
+            #[cfg(feature = "nsin_cusip")]
+            Self::Cusip => (9, 9),
+            #[cfg(feature = "nsin_sedol")]
+            Self::Sedol => (7, 7),
+            #[cfg(feature = "nsin_valoren")]
+            Self::Valor => (5, 9),
+            #[cfg(feature = "nsin_wkn")]
+            Self::Wkn => (6, 6),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for NationalSecuritiesNumber {
+    #[cfg_attr(
+        not(any(
+            feature = "nsin_cusip",
+            feature = "nsin_sedol",
+            feature = "nsin_valoren",
+            feature = "nsin_wkn"
+        )),
+        allow(unused_variables)
+    )]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            #[cfg(feature = "nsin_cusip")]
+            Self::Cusip(ref v) => write!(f, "{}", v),
+            #[cfg(feature = "nsin_sedol")]
+            Self::Sedol(ref v) => write!(f, "{}", v),
+            #[cfg(feature = "nsin_valoren")]
+            Self::Valor(ref v) => write!(f, "{}", v),
+            #[cfg(feature = "nsin_wkn")]
+            Self::Wkn(ref v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl NationalSecuritiesNumber {
+    ///
+    /// Return the scheme this number belongs to.
+    ///
+    pub fn scheme(&self) -> NsinScheme {
+        match *self {
+            #[cfg(feature = "nsin_cusip")]
+            Self::Cusip(_) => NsinScheme::Cusip,
+            #[cfg(feature = "nsin_sedol")]
+            Self::Sedol(_) => NsinScheme::Sedol,
+            #[cfg(feature = "nsin_valoren")]
+            Self::Valor(_) => NsinScheme::Valor,
+            #[cfg(feature = "nsin_wkn")]
+            Self::Wkn(_) => NsinScheme::Wkn,
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -19,3 +262,69 @@ pub mod sedol;
 
 #[cfg(feature = "nsin_valoren")]
 pub mod valoren;
+
+#[cfg(feature = "nsin_wkn")]
+pub mod wkn;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "nsin"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheme_for_country() {
+        assert_eq!(
+            national_number_scheme_for(&CountryCode::CA),
+            Some(NsinScheme::Cusip)
+        );
+        assert_eq!(
+            national_number_scheme_for(&CountryCode::IE),
+            Some(NsinScheme::Sedol)
+        );
+        assert_eq!(
+            national_number_scheme_for(&CountryCode::LI),
+            Some(NsinScheme::Valor)
+        );
+        assert_eq!(
+            national_number_scheme_for(&CountryCode::DE),
+            Some(NsinScheme::Wkn)
+        );
+        assert_eq!(national_number_scheme_for(&CountryCode::AU), None);
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        assert_eq!(
+            NsinScheme::Sedol
+                .parse_padded("000263494")
+                .unwrap()
+                .to_string(),
+            "0263494"
+        );
+        assert_eq!(
+            NsinScheme::Valor
+                .parse_padded("001213853")
+                .unwrap()
+                .to_string(),
+            "1213853"
+        );
+        assert_eq!(
+            NsinScheme::Wkn
+                .parse_padded("000723610")
+                .unwrap()
+                .to_string(),
+            "723610"
+        );
+        assert_eq!(
+            NsinScheme::Cusip
+                .parse_padded("037833100")
+                .unwrap()
+                .to_string(),
+            "037833100"
+        );
+        assert!(NsinScheme::Sedol.parse_padded("100263494").is_err());
+    }
+}
@@ -28,6 +28,7 @@ use serde::{Deserialize, Serialize};
 ///
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Sedol(String);
 
 pub const NATIONAL_ASSIGNMENT_AGENCY: &str = "London Stock Exchange";
@@ -69,6 +70,14 @@ impl FromStr for Sedol {
     }
 }
 
+impl TryFrom<String> for Sedol {
+    type Error = InternationalSecuritiesIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
@@ -82,4 +91,13 @@ mod tests {
     fn test_sedol_is_valid() {
         assert!(Sedol::is_valid("0263494"));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sedol_serde() {
+        let sedol: Sedol = serde_json::from_str("\"0263494\"").unwrap();
+        assert_eq!(serde_json::to_string(&sedol).unwrap(), "\"0263494\"");
+        assert!(serde_json::from_str::<Sedol>("\"x\"").is_err());
+        assert!(serde_json::from_str::<Sedol>("\"0263495\"").is_err());
+    }
 }
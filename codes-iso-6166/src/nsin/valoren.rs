@@ -31,7 +31,8 @@ use serde::{Deserialize, Serialize};
 ///
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-struct Valor(String);
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Valor(String);
 
 pub const NATIONAL_ASSIGNMENT_AGENCY: &str = "SIX Financial";
 
@@ -68,6 +69,14 @@ impl FromStr for Valor {
     }
 }
 
+impl TryFrom<String> for Valor {
+    type Error = InternationalSecuritiesIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
@@ -81,4 +90,12 @@ mod tests {
     fn test_valoren_is_valid() {
         assert!(Valor::is_valid("1213853"));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_valoren_serde() {
+        let valor: Valor = serde_json::from_str("\"1213853\"").unwrap();
+        assert_eq!(serde_json::to_string(&valor).unwrap(), "\"1213853\"");
+        assert!(serde_json::from_str::<Valor>("\"x\"").is_err());
+    }
 }
@@ -0,0 +1,111 @@
+/*!
+Provides the [Wkn] type for the German Wertpapierkennnummer (WKN).
+
+A WKN is made up of 6 digits or upper case letters, the letters `'I'` and
+`'O'` are not used to avoid confusion with `'1'` and `'0'`. Unlike other
+national numbers the WKN does not include a check digit.
+
+# Example
+
+```rust
+use codes_iso_6166::nsin::wkn::Wkn;
+use std::str::FromStr;
+
+let siemens = Wkn::from_str("723610").unwrap();
+assert_eq!(siemens.to_string(), "723610");
+```
+
+*/
+
+use crate::InternationalSecuritiesIdError;
+use codes_common::error::{invalid_format, invalid_length};
+use codes_common::{code_as_str, code_impl, fixed_length_code, FixedLengthCode};
+use std::str::FromStr;
+use tracing::warn;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// See <https://en.wikipedia.org/wiki/Wertpapierkennnummer>
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Wkn(String);
+
+pub const NATIONAL_ASSIGNMENT_AGENCY: &str = "WM Datenservice";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const TYPE_NAME: &str = "WKN";
+
+code_impl!(Wkn, as_str, str, String, to_string);
+
+code_as_str!(Wkn);
+
+fixed_length_code!(Wkn, 6);
+
+impl FromStr for Wkn {
+    type Err = InternationalSecuritiesIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != Self::fixed_length() {
+            warn!(
+                "{} must be {} characters long, not {}",
+                TYPE_NAME,
+                Self::fixed_length(),
+                s.len()
+            );
+            Err(invalid_length(TYPE_NAME, s.len()))
+        } else if !s
+            .chars()
+            .all(|c| matches!(c, '0'..='9' | 'A'..='H' | 'J'..='N' | 'P'..='Z'))
+        {
+            Err(invalid_format(TYPE_NAME, s))
+        } else {
+            Ok(Wkn(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<String> for Wkn {
+    type Error = InternationalSecuritiesIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::nsin::wkn::Wkn;
+    use codes_common::Code;
+
+    #[test]
+    fn test_wkn_is_valid() {
+        assert!(Wkn::is_valid("723610"));
+        assert!(Wkn::is_valid("A1EWWW"));
+        assert!(!Wkn::is_valid("A1EWWO"));
+        assert!(!Wkn::is_valid("72361"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_wkn_serde() {
+        let wkn: Wkn = serde_json::from_str("\"723610\"").unwrap();
+        assert_eq!(serde_json::to_string(&wkn).unwrap(), "\"723610\"");
+        assert!(serde_json::from_str::<Wkn>("\"x\"").is_err());
+        assert!(serde_json::from_str::<Wkn>("\"A1EWWO\"").is_err());
+    }
+}
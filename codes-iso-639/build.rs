@@ -1,3 +1,4 @@
+use chrono::{Datelike, NaiveDate};
 use codes_common::build::csv::{open_csv_file, process_tsv_input};
 use codes_common::build::{
     default_finalize_for, default_init, make_default_renderer, process, Data as DataTrait, DataRow,
@@ -7,16 +8,24 @@ use csv::StringRecord;
 use std::collections::BTreeMap;
 use tera::{Map, Value};
 
+// (part 3, part 2B, part 2T, part 1)
+type CrossReference = (String, String, String, String);
+
 #[derive(Debug, Default)]
 struct Data {
     rows: BTreeMap<String, Map<String, Value>>,
     macros: Map<String, Value>,
+    retirements: Map<String, Value>,
+    names: Map<String, Value>,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     process(
         default_init,
-        |data: Data| process_tsv_input(data, "iso-639-1.tsv", process_part1_row),
+        |data: Data| {
+            process_tsv_input(data, "iso-639-1.tsv", process_part1_row)
+                .and_then(process_part1_cross_references)
+        },
         default_finalize_for,
         make_default_renderer("part_1._rs", "part_1.rs"),
     )?;
@@ -24,11 +33,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     process(
         default_init,
         |data: Data| {
-            process_tsv_input(data, "iso-639-2.tsv", process_part2_row).map(|mut data| {
-                // qaa-qtz range is manually implemented in template
-                data.rows.remove("qaa-qtz");
-                data
-            })
+            process_tsv_input(data, "iso-639-2.tsv", process_part2_row)
+                .map(|mut data| {
+                    // qaa-qtz range is manually implemented in template
+                    data.rows.remove("qaa-qtz");
+                    data
+                })
+                .and_then(process_part2_cross_references)
         },
         default_finalize_for,
         make_default_renderer("part_2._rs", "part_2.rs"),
@@ -39,6 +50,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         |data| {
             process_tsv_input(data, "iso-639-3.tsv", process_part3_row)
                 .and_then(process_part3_macro_csv)
+                .and_then(process_part3_cross_references)
+                .and_then(process_part3_retirements_csv)
+                .and_then(process_part3_name_index_csv)
         },
         finalize_part3,
         make_default_renderer("part_3._rs", "part_3.rs"),
@@ -153,6 +167,74 @@ fn process_part3_row(
     Ok(id)
 }
 
+// The ISO 639-3 table is the only source that links codes across the parts.
+fn read_part3_cross_references() -> Result<Vec<CrossReference>, Box<dyn std::error::Error>> {
+    let mut rdr = open_csv_file("iso-639-3.tsv", Some(b'\t'))?;
+
+    let mut references: Vec<CrossReference> = Default::default();
+    for result in rdr.records() {
+        let record = result?;
+        references.push((
+            record.get(0).unwrap().to_string(),
+            record.get(1).unwrap().to_string(),
+            record.get(2).unwrap().to_string(),
+            record.get(3).unwrap().to_string(),
+        ));
+    }
+
+    Ok(references)
+}
+
+fn process_part1_cross_references(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    for (part_3_code, _, _, part_1_code) in read_part3_cross_references()? {
+        if let Some(row) = data.rows.get_mut(&part_1_code) {
+            insert_field!(part_3_code => row, "part_3_code");
+        }
+    }
+
+    Ok(data)
+}
+
+fn process_part2_cross_references(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    for (part_3_code, part_2b_code, part_2t_code, _) in read_part3_cross_references()? {
+        if let Some(row) = data.rows.get_mut(&part_2t_code) {
+            insert_field!(part_3_code.clone() => row, "part_3_code");
+            if part_2b_code != part_2t_code {
+                insert_field!(part_2b_code.clone() => row, "bibliographic_code");
+            }
+        }
+        if part_2b_code != part_2t_code {
+            if let Some(row) = data.rows.get_mut(&part_2b_code) {
+                insert_field!(part_3_code => row, "part_3_code");
+                insert_field!(part_2t_code => row, "terminologic_code");
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+fn process_part3_cross_references(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    let mut rdr = open_csv_file("iso-639-2.tsv", Some(b'\t'))?;
+    let mut part_2_codes: Vec<String> = Default::default();
+    for result in rdr.records() {
+        part_2_codes.push(result?.get(1).unwrap().to_string());
+    }
+
+    for (part_3_code, part_2b_code, part_2t_code, _) in read_part3_cross_references()? {
+        let row = data.rows.get_mut(&part_3_code).unwrap();
+        // New codes may not yet be present in the ISO 639-2 table.
+        if part_2_codes.contains(&part_2b_code) {
+            insert_field!(part_2b_code => row, "part_2b_code");
+        }
+        if part_2_codes.contains(&part_2t_code) {
+            insert_field!(part_2t_code => row, "part_2t_code");
+        }
+    }
+
+    Ok(data)
+}
+
 fn process_part3_macro_csv(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
     let mut rdr = open_csv_file("iso-639-3-macro-languages.tsv", Some(b'\t'))?;
 
@@ -174,11 +256,121 @@ fn process_part3_macro_csv(mut data: Data) -> Result<Data, Box<dyn std::error::E
     Ok(data)
 }
 
+fn process_part3_retirements_csv(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    let mut rdr = open_csv_file("iso-639-3-retirements.tsv", Some(b'\t'))?;
+
+    let mut change_to: BTreeMap<String, String> = Default::default();
+    for result in rdr.records() {
+        let record = result?;
+
+        let id = record.get(0).unwrap().to_string();
+
+        let mut row: DataRow = Default::default();
+        insert_field!(record, 1 => row, "ref_name");
+        insert_field!(
+            match record.get(2).unwrap() {
+                "C" => "Change",
+                "D" => "Duplicate",
+                "N" => "NonExistent",
+                "S" => "Split",
+                "M" => "Merge",
+                _ => unreachable!(),
+            }
+            => row, "reason"
+        );
+        insert_optional_field!(record, 4 => row, "remedy");
+        insert_field!(record, 5 => row, "effective");
+        let (year, month, day) = parse_effective_date(record.get(5).unwrap())
+            .map_err(|e| format!("Invalid effective date for {}: {}", id, e))?;
+        row.insert("effective_year".to_string(), year.into());
+        row.insert("effective_month".to_string(), month.into());
+        row.insert("effective_day".to_string(), day.into());
+
+        match record.get(3).unwrap() {
+            "" => {
+                // Splits name the new codes in the remedy text, as `[abc]`.
+                let remedy = record.get(4).unwrap();
+                let codes: Vec<Value> = remedy
+                    .split('[')
+                    .skip(1)
+                    .filter_map(|s| s.split_once(']'))
+                    .map(|(code, _)| code)
+                    .filter(|code| data.rows.contains_key(*code))
+                    .map(|code| code.into())
+                    .collect();
+                insert_field!(codes => row, "replacements");
+            }
+            code => {
+                change_to.insert(id.clone(), code.to_string());
+            }
+        }
+
+        data.retirements.insert(id, Value::Object(row));
+    }
+
+    // A code may be changed to one that was itself retired later, so follow
+    // the chain to a current code.
+    for (id, code) in &change_to {
+        let mut code = code;
+        for _ in 0..change_to.len() {
+            match change_to.get(code) {
+                Some(next) if !data.rows.contains_key(code) => code = next,
+                _ => break,
+            }
+        }
+        let replacements: Vec<Value> = if data.rows.contains_key(code) {
+            vec![code.clone().into()]
+        } else {
+            Default::default()
+        };
+        let row = data
+            .retirements
+            .get_mut(id)
+            .unwrap()
+            .as_object_mut()
+            .unwrap();
+        insert_field!(replacements => row, "replacements");
+    }
+
+    Ok(data)
+}
+
+fn parse_effective_date(s: &str) -> Result<(i32, u32, u32), Box<dyn std::error::Error>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")?;
+    Ok((date.year(), date.month(), date.day()))
+}
+
+fn process_part3_name_index_csv(mut data: Data) -> Result<Data, Box<dyn std::error::Error>> {
+    let mut rdr = open_csv_file("iso-639-3-name-index.tsv", Some(b'\t'))?;
+
+    for result in rdr.records() {
+        let record = result?;
+
+        let mut name: DataRow = Default::default();
+        insert_field!(record, 1 => name, "print_name");
+        insert_field!(record, 2 => name, "inverted_name");
+
+        let id = record.get(0).unwrap();
+        if let Some(Value::Array(names)) = data.names.get_mut(id) {
+            names.push(Value::Object(name));
+        } else {
+            data.names
+                .insert(id.to_string(), Value::Array(vec![Value::Object(name)]));
+        }
+    }
+
+    Ok(data)
+}
+
 fn finalize_part3(data: Data) -> std::result::Result<tera::Context, Box<dyn std::error::Error>> {
     let macros = data.macros.clone();
+    let retirements = data.retirements.clone();
+    let names = data.names.clone();
     let mut ctx = default_finalize_for(data)?;
 
     ctx.insert("macro_langs", &macros);
+    ctx.insert("retirements", &retirements);
+    ctx.insert("name_index", &names);
 
     Ok(ctx)
 }
@@ -206,6 +398,8 @@ impl DataTrait for Data {
         Self {
             rows: Default::default(),
             macros: Default::default(),
+            retirements: Default::default(),
+            names: Default::default(),
         }
     }
 
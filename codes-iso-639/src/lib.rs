@@ -49,14 +49,28 @@ assert_eq!(code.language_name(), "French");
 By default only the `serde` feature is enabled, and [part_1] two-letter
 language codes.
 
-* `serde` - Enables serialization of the different Language Code types.
+* `serde` - Enables serialization of the different Language Code types, as
+  their code strings. With `part_3` also adds the `part_3::part_1` module, and
+  with `part_2` the `part_3::part_2b` module, for use with
+  `#[serde(with = "...")]`.
 * `part_3` - Adds the ISO 639-3 three-letter language codes.
   * `comment` - Adds the `LanguageCode::comment` method.
   * `language_type` - Adds the `LanguageCode::language_type` method.
   * `macro_individuals` - Adds the `LanguageCode::macro_individuals` method.
   * `scope` - Adds the `LanguageCode::scope` method.
+  * `retirements` - Adds the `RetiredLanguageCode` type and the
+    `LanguageCode::from_str_resolved` method (requires package `chrono`).
+  * `name_index` - Adds the `LanguageCode::index_names` method.
 * `part_5` - Adds the ISO 639-5 three-letter language family or group codes.
 
+Codes may be converted between the parts, where the corresponding features are
+enabled; `part_1::LanguageCode::part_3_code`,
+`part_2::LanguageCode::part_3_code`, `part_3::LanguageCode::part_1_code`,
+`part_3::LanguageCode::part_2b_code`, and `part_3::LanguageCode::part_2t_code`.
+The bibliographic and terminologic forms of ISO 639-2 codes, such as `fre`
+and `fra`, are available from `part_2::LanguageCode::bibliographic_code` and
+`part_2::LanguageCode::terminologic_code`.
+
 */
 
 #![warn(
@@ -88,7 +102,8 @@ language codes.
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
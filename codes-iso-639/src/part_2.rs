@@ -5,7 +5,7 @@ representation of names of languages — Part 2: Alpha-3 code.
 
 #[cfg(test)]
 mod tests {
-    use crate::{part_2::LanguageCode, LanguageCodeError};
+    use crate::part_2::LanguageCode;
     use std::str::FromStr;
 
     #[test]
@@ -21,6 +21,55 @@ mod tests {
         assert_eq!(LanguageCode::from_str("qtz").unwrap(), LanguageCode::Reserved);
         assert!(LanguageCode::from_str("qua").is_err());
     }
+
+    #[test]
+    fn bibliographic_and_terminologic() {
+        assert_eq!(LanguageCode::Fra.bibliographic_code(), LanguageCode::Fre);
+        assert_eq!(LanguageCode::Fre.terminologic_code(), LanguageCode::Fra);
+        assert_eq!(LanguageCode::Fre.bibliographic_code(), LanguageCode::Fre);
+        assert_eq!(LanguageCode::Eng.terminologic_code(), LanguageCode::Eng);
+    }
+
+    #[cfg(feature = "part_3")]
+    #[test]
+    fn part_3_code() {
+        use crate::part_3::LanguageCode as Part3;
+
+        assert_eq!(LanguageCode::Fre.part_3_code(), Some(Part3::Fra));
+        assert_eq!(LanguageCode::Fra.part_3_code(), Some(Part3::Fra));
+        assert_eq!(LanguageCode::Sla.part_3_code(), None);
+        assert_eq!(LanguageCode::Reserved.part_3_code(), None);
+    }
+
+    #[test]
+    fn ordinals() {
+        use codes_common::CodeEnum;
+
+        assert_eq!(LanguageCode::count(), LanguageCode::all().len());
+        for (i, code) in LanguageCode::all().iter().enumerate() {
+            assert_eq!(code.ordinal(), i);
+            assert_eq!(LanguageCode::from_ordinal(i), Some(*code));
+        }
+        assert_eq!(
+            LanguageCode::from_ordinal(LanguageCode::count() - 1),
+            Some(LanguageCode::Reserved)
+        );
+        assert_eq!(LanguageCode::from_ordinal(LanguageCode::count()), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_code_string() {
+        assert_eq!(
+            serde_json::to_string(&LanguageCode::Fre).unwrap(),
+            r#""fre""#
+        );
+        for code in crate::part_2::ALL_CODES {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(serde_json::from_str::<LanguageCode>(&json).unwrap(), code);
+        }
+        assert!(serde_json::from_str::<LanguageCode>(r#""fr""#).is_err());
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -172,6 +172,76 @@ pub enum LanguageType {
     Special,
 }
 
+///
+/// The reason recorded for the retirement of a [RetiredLanguageCode].
+///
+#[cfg(feature = "retirements")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum RetirementReason {
+    /// The code was changed, for example `fri` became `fry`.
+    Change,
+    /// The code was a duplicate of another code.
+    Duplicate,
+    /// The language was found not to exist.
+    NonExistent,
+    /// The language was split into two or more new languages.
+    Split,
+    /// The language was merged into an existing language.
+    Merge,
+}
+
+///
+/// An entry in the ISO 639-3 name index, the print name is the name as
+/// commonly written while the inverted name places the root of the name
+/// first, for example "Algerian Saharan Arabic" and "Arabic, Algerian
+/// Saharan".
+///
+#[cfg(feature = "name_index")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexName {
+    print_name: &'static str,
+    inverted_name: &'static str,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "retirements")]
+impl LanguageCode {
+    ///
+    /// Parse a three-letter code as [LanguageCode::from_str] does, but also
+    /// accept a retired code where it was replaced by a single current code;
+    /// for example `fri` resolves to `fry`, and `auv` to `oci`. Retired codes
+    /// that were split, or that were found to not exist, are not resolved.
+    ///
+    pub fn from_str_resolved(s: &str) -> Result<Self, LanguageCodeError> {
+        Self::from_str(s).or_else(|e| match RetiredLanguageCode::from_str(s) {
+            Ok(retired) if retired.reason() != RetirementReason::Split => {
+                match retired.replacements() {
+                    [replacement] => Ok(*replacement),
+                    _ => Err(e),
+                }
+            }
+            _ => Err(e),
+        })
+    }
+}
+
+#[cfg(feature = "name_index")]
+impl IndexName {
+    /// Returns the name as commonly written.
+    pub const fn print_name(&self) -> &'static str {
+        self.print_name
+    }
+
+    /// Returns the name with its root first, for sorting.
+    pub const fn inverted_name(&self) -> &'static str {
+        self.inverted_name
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 //
 // The rest of this file is generated by the package build script.
@@ -179,3 +249,134 @@ pub enum LanguageType {
 // ------------------------------------------------------------------------------------------------
 
 include!(concat!(env!("OUT_DIR"), "/part_3.rs"));
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+pub mod part_1;
+
+#[cfg(all(feature = "serde", feature = "part_2"))]
+pub mod part_2b;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "retirements", feature = "name_index"))]
+    use crate::part_3::*;
+
+    #[cfg(feature = "retirements")]
+    use chrono::NaiveDate;
+
+    #[test]
+    fn part_1_code() {
+        use crate::part_1::LanguageCode as Part1;
+        use crate::part_3::LanguageCode;
+
+        assert_eq!(LanguageCode::Fra.part_1_code(), Some(Part1::Fr));
+        assert_eq!(Part1::Fr.part_3_code(), Some(LanguageCode::Fra));
+        assert_eq!(LanguageCode::Aaa.part_1_code(), None);
+    }
+
+    #[cfg(feature = "part_2")]
+    #[test]
+    fn part_2_codes() {
+        use crate::part_2::LanguageCode as Part2;
+        use crate::part_3::LanguageCode;
+
+        assert_eq!(LanguageCode::Fra.part_2b_code(), Some(Part2::Fre));
+        assert_eq!(LanguageCode::Fra.part_2t_code(), Some(Part2::Fra));
+        assert_eq!(LanguageCode::Eng.part_2b_code(), Some(Part2::Eng));
+        assert_eq!(LanguageCode::Aaa.part_2t_code(), None);
+    }
+
+    #[test]
+    fn defining_standard() {
+        use crate::part_3::ISO_639_3;
+        use codes_agency::Agency;
+
+        assert_eq!(ISO_639_3.edition(), Some("2007"));
+        assert_eq!(ISO_639_3.registration_authority(), Some(Agency::SIL));
+    }
+
+    #[cfg(feature = "part_2")]
+    #[test]
+    fn defining_standard_supersedes_part_2() {
+        use crate::part_2::ISO_639_2;
+        use crate::part_3::ISO_639_3;
+
+        assert_eq!(ISO_639_2.long_ref(), Some(&"ISO 639-2:1998"));
+        assert_eq!(ISO_639_3.supersedes(), Some(&ISO_639_2));
+        assert_eq!(ISO_639_2.supersedes(), None);
+    }
+
+    #[cfg(feature = "retirements")]
+    #[test]
+    fn retired_codes() {
+        let code = RetiredLanguageCode::from_str("fri").unwrap();
+        assert_eq!(code.reason(), RetirementReason::Change);
+        assert_eq!(code.effective_date_string(), "2007-02-01");
+        assert_eq!(
+            code.effective_date(),
+            NaiveDate::from_ymd_opt(2007, 2, 1).unwrap()
+        );
+        assert!(code.effective_date() < RetiredLanguageCode::Amd.effective_date());
+        assert_eq!(code.replacements(), &[LanguageCode::Fry]);
+
+        let code = RetiredLanguageCode::from_str("ccy").unwrap();
+        assert_eq!(code.reason(), RetirementReason::Split);
+        assert_eq!(code.replacements().len(), 5);
+        assert!(code.replacements().contains(&LanguageCode::Zhn));
+
+        let code = RetiredLanguageCode::from_str("amd").unwrap();
+        assert_eq!(code.reason(), RetirementReason::NonExistent);
+        assert!(code.replacements().is_empty());
+    }
+
+    #[cfg(feature = "retirements")]
+    #[test]
+    fn from_str_resolved() {
+        assert!(LanguageCode::from_str("fri").is_err());
+        assert_eq!(
+            LanguageCode::from_str_resolved("fri").unwrap(),
+            LanguageCode::Fry
+        );
+        assert_eq!(
+            LanguageCode::from_str_resolved("auv").unwrap(),
+            LanguageCode::Oci
+        );
+        assert_eq!(
+            LanguageCode::from_str_resolved("eng").unwrap(),
+            LanguageCode::Eng
+        );
+        assert!(LanguageCode::from_str_resolved("ccy").is_err());
+        assert!(LanguageCode::from_str_resolved("amd").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_code_string() {
+        use crate::part_3::{LanguageCode, ALL_CODES};
+
+        assert_eq!(
+            serde_json::to_string(&LanguageCode::Eng).unwrap(),
+            r#""eng""#
+        );
+        for code in ALL_CODES {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(serde_json::from_str::<LanguageCode>(&json).unwrap(), code);
+        }
+    }
+
+    #[cfg(feature = "name_index")]
+    #[test]
+    fn index_names() {
+        let names = LanguageCode::Aao.index_names();
+        assert_eq!(names[0].print_name(), "Algerian Saharan Arabic");
+        assert_eq!(names[0].inverted_name(), "Arabic, Algerian Saharan");
+    }
+}
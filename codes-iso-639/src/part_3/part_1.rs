@@ -0,0 +1,110 @@
+/*!
+Provides functions for use with the `#[serde(with = "...")]` attribute to
+represent a [LanguageCode] by its ISO 639-1 two-letter code, where one exists.
+
+Languages without a two-letter code are represented by their ISO 639-3
+three-letter code, and either form is accepted when deserializing.
+
+# Example
+
+```rust
+use codes_iso_639::part_3::LanguageCode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Document {
+    #[serde(with = "codes_iso_639::part_3::part_1")]
+    language: LanguageCode,
+}
+
+let document = Document { language: LanguageCode::Fra };
+let json = serde_json::to_string(&document).unwrap();
+assert_eq!(json, r#"{"language":"fr"}"#);
+assert_eq!(serde_json::from_str::<Document>(&json).unwrap(), document);
+
+let document = Document { language: LanguageCode::Aaa };
+let json = serde_json::to_string(&document).unwrap();
+assert_eq!(json, r#"{"language":"aaa"}"#);
+```
+
+*/
+
+use super::LanguageCode;
+use crate::part_1::LanguageCode as Part1;
+use serde::de::{Error as _, Unexpected};
+use serde::{Deserialize, Deserializer, Serializer};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serialize `code` as its ISO 639-1 code, or its ISO 639-3 code if it has no
+/// ISO 639-1 code.
+///
+pub fn serialize<S>(code: &LanguageCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match code.part_1_code() {
+        Some(part_1_code) => serializer.collect_str(&part_1_code),
+        None => serializer.collect_str(code),
+    }
+}
+
+///
+/// Deserialize a [LanguageCode] from either its ISO 639-1 or ISO 639-3 code.
+///
+pub fn deserialize<'de, D>(deserializer: D) -> Result<LanguageCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Part1::from_str(&s)
+        .ok()
+        .and_then(|code| code.part_3_code())
+        .or_else(|| LanguageCode::from_str(&s).ok())
+        .ok_or_else(|| {
+            D::Error::invalid_value(
+                Unexpected::Str(&s),
+                &"an ISO 639-1 or ISO 639-3 language code",
+            )
+        })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part_3::ALL_CODES;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper(#[serde(with = "super")] LanguageCode);
+
+    #[test]
+    fn test_part_1_round_trip() {
+        for code in ALL_CODES {
+            let json = serde_json::to_string(&Wrapper(code)).unwrap();
+            assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap().0, code);
+        }
+    }
+
+    #[test]
+    fn test_part_1_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""en""#).unwrap().0,
+            LanguageCode::Eng
+        );
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(r#""eng""#).unwrap().0,
+            LanguageCode::Eng
+        );
+        assert!(serde_json::from_str::<Wrapper>(r#""EN""#).is_err());
+        assert!(serde_json::from_str::<Wrapper>(r#""zz""#).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+/*!
+Provides functions for use with the `#[serde(with = "...")]` attribute to
+represent a [LanguageCode] by its ISO 639-2 bibliographic (2B) code, where one
+exists.
+
+Languages without an ISO 639-2 code are represented by their ISO 639-3 code,
+and either form, as well as the ISO 639-2 terminologic (2T) code, is accepted
+when deserializing.
+
+# Example
+
+```rust
+use codes_iso_639::part_3::LanguageCode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Record {
+    #[serde(with = "codes_iso_639::part_3::part_2b")]
+    language: LanguageCode,
+}
+
+let record = Record { language: LanguageCode::Fra };
+let json = serde_json::to_string(&record).unwrap();
+assert_eq!(json, r#"{"language":"fre"}"#);
+assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+assert_eq!(
+    serde_json::from_str::<Record>(r#"{"language":"fra"}"#).unwrap(),
+    record
+);
+```
+
+*/
+
+use super::LanguageCode;
+use crate::part_2::LanguageCode as Part2;
+use serde::de::{Error as _, Unexpected};
+use serde::{Deserialize, Deserializer, Serializer};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serialize `code` as its ISO 639-2 bibliographic code, or its ISO 639-3
+/// code if it has no ISO 639-2 code.
+///
+pub fn serialize<S>(code: &LanguageCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match code.part_2b_code() {
+        Some(part_2b_code) => serializer.collect_str(&part_2b_code),
+        None => serializer.collect_str(code),
+    }
+}
+
+///
+/// Deserialize a [LanguageCode] from either its ISO 639-2 or ISO 639-3 code.
+///
+pub fn deserialize<'de, D>(deserializer: D) -> Result<LanguageCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Part2::from_str(&s)
+        .ok()
+        .and_then(|code| code.part_3_code())
+        .or_else(|| LanguageCode::from_str(&s).ok())
+        .ok_or_else(|| {
+            D::Error::invalid_value(
+                Unexpected::Str(&s),
+                &"an ISO 639-2 or ISO 639-3 language code",
+            )
+        })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part_3::ALL_CODES;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Wrapper(#[serde(with = "super")] LanguageCode);
+
+    #[test]
+    fn test_part_2b_round_trip() {
+        for code in ALL_CODES {
+            let json = serde_json::to_string(&Wrapper(code)).unwrap();
+            assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap().0, code);
+        }
+    }
+
+    #[test]
+    fn test_part_2b_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Wrapper(LanguageCode::Deu)).unwrap(),
+            r#""ger""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Wrapper(LanguageCode::Eng)).unwrap(),
+            r#""eng""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Wrapper(LanguageCode::Aaa)).unwrap(),
+            r#""aaa""#
+        );
+    }
+}
@@ -0,0 +1,347 @@
+/*!
+This package contains an implementation of the
+[ISO 9362](https://www.iso.org/standard/84108.html)
+Business identifier code (BIC) specification.
+
+The Business Identifier Code (BIC), also commonly referred to as a SWIFT code,
+is an international standard for identifying financial and non-financial
+institutions, primarily used in the routing of payments and other financial
+messages. SWIFT acts as the registration authority for BICs, and the ISO
+Specification defines the following components:
+
+1. Characters 1-4: Party prefix, identifying the institution.
+2. Characters 5-6: The ISO 3166-1 alpha-2 code of the country in which the
+   institution is located, or `XK` for Kosovo which is used by SWIFT but is
+   not assigned in ISO 3166-1.
+3. Characters 7-8: Party suffix, formerly the location code. A `'0'` as the
+   second character denotes a test BIC.
+4. Characters 9-11: Optional branch code, where `XXX` denotes the primary
+   office.
+
+# Example
+
+```rust
+use codes_iso_9362::BusinessIdentifierCode;
+use codes_iso_3166::part_1::CountryCode;
+use std::str::FromStr;
+
+let bic = BusinessIdentifierCode::from_str("DEUTDEFF500").unwrap();
+
+assert_eq!(bic.party_prefix(), "DEUT");
+assert_eq!(bic.country_code(), Some(CountryCode::DE));
+assert_eq!(bic.party_suffix(), "FF");
+assert_eq!(bic.branch_code(), Some("500"));
+assert!(!bic.is_primary_office());
+assert!(!bic.is_test());
+```
+
+# Features
+
+By default only the `serde` feature is enabled.
+
+* `serde` - Enables serialization of the [BusinessIdentifierCode] type.
+
+*/
+
+#![warn(
+    unknown_lints,
+    // ---------- Stylistic
+    absolute_paths_not_starting_with_crate,
+    elided_lifetimes_in_paths,
+    explicit_outlives_requirements,
+    macro_use_extern_crate,
+    nonstandard_style, /* group */
+    noop_method_call,
+    rust_2018_idioms,
+    single_use_lifetimes,
+    trivial_casts,
+    trivial_numeric_casts,
+    // ---------- Future
+    future_incompatible, /* group */
+    rust_2021_compatibility, /* group */
+    // ---------- Public
+    missing_debug_implementations,
+    // missing_docs,
+    unreachable_pub,
+    // ---------- Unsafe
+    unsafe_code,
+    unsafe_op_in_unsafe_fn,
+    // ---------- Unused
+    unused, /* group */
+)]
+#![deny(
+    // ---------- Public
+    exported_private_dependencies,
+    private_interfaces,
+    private_bounds,
+    // ---------- Deprecated
+    anonymous_parameters,
+    bare_trait_objects,
+    ellipsis_inclusive_range_patterns,
+    // ---------- Unsafe
+    deref_nullptr,
+    drop_bounds,
+    dyn_drop,
+)]
+
+use codes_agency::{standardized_type, Agency, Standard};
+use codes_common::error::{invalid_character, invalid_format, invalid_length, unknown_value};
+use codes_common::{code_as_str, code_impl, variable_length_code};
+use codes_iso_3166::part_1::CountryCode;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An instance of the `Standard` struct defined in the
+/// [`codes_agency`](https://docs.rs/codes-agency/latest/codes_agency/)
+/// package that describes the ISO-9362 specification.
+///
+pub const ISO_9362: Standard = Standard::new_with_long_ref(
+    Agency::ISO,
+    "9362",
+    "ISO 9362:2022",
+    "Banking — Banking telecommunication messages — Business identifier code (BIC)",
+    "https://www.iso.org/standard/84108.html",
+)
+.with_edition("2022")
+.with_publication_date("2022-05")
+.with_registration_authority(Agency::SWIFT);
+
+///
+/// The ISO 9362 Business Identifier Code (BIC) is either an 8 character code
+/// identifying an institution, or an 11 character code identifying a
+/// specific branch of an institution.
+///
+/// # Format
+///
+/// Note that the characters in the BIC are commonly numbered from left to
+/// right starting at one.
+///
+/// | Characters | Usage        | Alphabet      | Notes                           |
+/// | ---------- | ------------ | ------------- | ------------------------------- |
+/// | 1..4       | Party Prefix | alpha-numeric |                                 |
+/// | 5..6       | Country Code | alphabetic    | ISO 3166-1 alpha-2, or `XK`     |
+/// | 7..8       | Party Suffix | alpha-numeric | `'0'` as 8th denotes a test BIC |
+/// | 9..11      | Branch Code  | alpha-numeric | optional, `XXX` primary office  |
+///
+/// # Examples:
+///
+/// * `DEUTDEFF` - Deutsche Bank, Frankfurt
+/// * `DEUTDEFF500` - Deutsche Bank, Frankfurt, branch 500
+/// * `NWBKGB2LXXX` - National Westminster Bank, primary office
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct BusinessIdentifierCode(String);
+
+pub use codes_common::CodeParseError as BusinessIdentifierCodeError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const TYPE_NAME: &str = "BusinessIdentifierCode";
+
+const PRIMARY_OFFICE_BRANCH_CODE: &str = "XXX";
+
+// Kosovo is used by SWIFT, as it is in the IBAN registry, but is not (yet)
+// assigned in ISO 3166-1.
+const KOSOVO_COUNTRY_CODE: &str = "XK";
+
+impl TryFrom<String> for BusinessIdentifierCode {
+    type Error = BusinessIdentifierCodeError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+impl FromStr for BusinessIdentifierCode {
+    type Err = BusinessIdentifierCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 8 && s.len() != 11 {
+            Err(invalid_length(TYPE_NAME, s.len()))
+        } else if let Some(c) = s
+            .chars()
+            .find(|c| !(c.is_ascii_uppercase() || c.is_ascii_digit()))
+        {
+            Err(invalid_character(TYPE_NAME, c))
+        } else if &s[4..6] != KOSOVO_COUNTRY_CODE && CountryCode::from_str(&s[4..6]).is_err() {
+            Err(unknown_value(TYPE_NAME, &s[4..6]))
+        } else if s.len() == 11 && s[8..].starts_with('X') && &s[8..] != PRIMARY_OFFICE_BRANCH_CODE
+        {
+            // only the primary office branch code may start with 'X'.
+            Err(invalid_format(TYPE_NAME, s))
+        } else {
+            Ok(BusinessIdentifierCode(s.to_string()))
+        }
+    }
+}
+
+code_impl!(BusinessIdentifierCode, as_str, str, String, to_string);
+
+code_as_str!(BusinessIdentifierCode);
+
+variable_length_code!(BusinessIdentifierCode, 8, 11);
+
+standardized_type!(BusinessIdentifierCode, ISO_9362);
+
+impl BusinessIdentifierCode {
+    ///
+    /// Return the portion of the BIC that identifies the institution.
+    ///
+    pub fn party_prefix(&self) -> &str {
+        &self.0[..4]
+    }
+
+    ///
+    /// Return the country code in which the institution is located, or
+    /// `None` if the code is not an ISO 3166-1 country code, such as `XK`
+    /// for Kosovo.
+    ///
+    pub fn country_code(&self) -> Option<CountryCode> {
+        CountryCode::from_str(self.country_code_str()).ok()
+    }
+
+    ///
+    /// Return the two character country code portion of the BIC, this is
+    /// either an ISO 3166-1 alpha-2 code or `XK`.
+    ///
+    pub fn country_code_str(&self) -> &str {
+        &self.0[4..6]
+    }
+
+    ///
+    /// Return the portion of the BIC that, with the party prefix and
+    /// country code, identifies the institution; this was formerly known as
+    /// the location code.
+    ///
+    pub fn party_suffix(&self) -> &str {
+        &self.0[6..8]
+    }
+
+    ///
+    /// Return the branch code, if this is an 11 character BIC.
+    ///
+    pub fn branch_code(&self) -> Option<&str> {
+        if self.0.len() == 11 {
+            Some(&self.0[8..])
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns `true` if this BIC identifies the primary office of the
+    /// institution; either an 8 character BIC, or one with the branch code
+    /// `XXX`.
+    ///
+    pub fn is_primary_office(&self) -> bool {
+        matches!(self.branch_code(), None | Some(PRIMARY_OFFICE_BRANCH_CODE))
+    }
+
+    ///
+    /// Return the 8 character BIC of the primary office of the institution.
+    ///
+    pub fn primary_office(&self) -> Self {
+        Self(self.0[..8].to_string())
+    }
+
+    ///
+    /// Returns `true` if this is a test BIC, one with the character `'0'` as
+    /// the second character of the party suffix, else `false`.
+    ///
+    pub fn is_test(&self) -> bool {
+        self.0.as_bytes()[7] == b'0'
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::BusinessIdentifierCode;
+    use codes_iso_3166::part_1::CountryCode;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_valid_bic_8() {
+        let bic = BusinessIdentifierCode::from_str("DEUTDEFF").unwrap();
+        assert_eq!(bic.party_prefix(), "DEUT");
+        assert_eq!(bic.country_code(), Some(CountryCode::DE));
+        assert_eq!(bic.party_suffix(), "FF");
+        assert_eq!(bic.branch_code(), None);
+        assert!(bic.is_primary_office());
+        assert!(!bic.is_test());
+    }
+
+    #[test]
+    fn test_valid_bic_11() {
+        let bic = BusinessIdentifierCode::from_str("NWBKGB2LXXX").unwrap();
+        assert_eq!(bic.party_prefix(), "NWBK");
+        assert_eq!(bic.country_code(), Some(CountryCode::GB));
+        assert_eq!(bic.party_suffix(), "2L");
+        assert_eq!(bic.branch_code(), Some("XXX"));
+        assert!(bic.is_primary_office());
+        assert_eq!(bic.primary_office().to_string(), "NWBKGB2L");
+
+        let bic = BusinessIdentifierCode::from_str("DEUTDEFF500").unwrap();
+        assert!(!bic.is_primary_office());
+        assert_eq!(bic.primary_office().as_ref(), "DEUTDEFF");
+    }
+
+    #[test]
+    fn test_test_bic() {
+        let bic = BusinessIdentifierCode::from_str("ABCDUS30").unwrap();
+        assert!(bic.is_test());
+        assert!(bic.is_primary_office());
+    }
+
+    #[test]
+    fn test_invalid_bic() {
+        // length
+        assert!(BusinessIdentifierCode::from_str("DEUTDEF").is_err());
+        assert!(BusinessIdentifierCode::from_str("DEUTDEFF5").is_err());
+        // characters
+        assert!(BusinessIdentifierCode::from_str("deutdeff").is_err());
+        assert!(BusinessIdentifierCode::from_str("DEUT-EFF").is_err());
+        // country
+        assert!(BusinessIdentifierCode::from_str("DEUTZZFF").is_err());
+        // branch code
+        assert!(BusinessIdentifierCode::from_str("DEUTDEFFX01").is_err());
+    }
+
+    #[test]
+    fn test_kosovo_bic() {
+        let bic = BusinessIdentifierCode::from_str("RBKOXKPR").unwrap();
+        assert_eq!(bic.party_prefix(), "RBKO");
+        assert_eq!(bic.country_code(), None);
+        assert_eq!(bic.country_code_str(), "XK");
+        assert_eq!(bic.party_suffix(), "PR");
+        assert!(BusinessIdentifierCode::from_str("RBKOXXPR").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let bic = BusinessIdentifierCode::from_str("DEUTDEFF500").unwrap();
+        let json = serde_json::to_string(&bic).unwrap();
+        assert_eq!(json, r#""DEUTDEFF500""#);
+        assert_eq!(
+            serde_json::from_str::<BusinessIdentifierCode>(&json).unwrap(),
+            bic
+        );
+        assert!(serde_json::from_str::<BusinessIdentifierCode>(r#""x""#).is_err());
+        assert!(serde_json::from_str::<BusinessIdentifierCode>(r#""DEUTZZFF""#).is_err());
+    }
+}
@@ -59,7 +59,7 @@ fn process_input_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::e
         let country_name = strings.remove(8);
         strings.insert(9, country_name);
 
-        let code = strings.get(0).unwrap();
+        let code = strings.first().unwrap();
         if !code.is_empty() && !data.contains(code) {
             let mut row: Map<String, Value> = Default::default();
 
@@ -96,7 +96,7 @@ fn process_input_data(mut data: SimpleData) -> Result<SimpleData, Box<dyn std::e
 
             row.insert(
                 "parent_code".to_string(),
-                strings.get(0).unwrap().to_string().into(),
+                strings.first().unwrap().to_string().into(),
             );
 
             data.insert_row(code, row);
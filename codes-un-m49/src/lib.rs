@@ -82,7 +82,8 @@ By default only the `serde` feature is enabled.
 #![deny(
     // ---------- Public
     exported_private_dependencies,
-    private_in_public,
+    private_interfaces,
+    private_bounds,
     // ---------- Deprecated
     anonymous_parameters,
     bare_trait_objects,
@@ -108,3 +109,33 @@ include!(concat!(env!("OUT_DIR"), "/generated.rs"));
 #[doc(hidden)]
 mod kinds;
 pub use kinds::RegionKind;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            RegionClassificationCode::try_from(258).unwrap(),
+            UN_M69_REGION_258
+        );
+        assert!(RegionClassificationCode::try_from(9999).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&UN_M69_REGION_258).unwrap();
+        assert_eq!(json, "258");
+        assert_eq!(
+            serde_json::from_str::<RegionClassificationCode>(&json).unwrap(),
+            UN_M69_REGION_258
+        );
+        assert!(serde_json::from_str::<RegionClassificationCode>("9999").is_err());
+    }
+}